@@ -42,6 +42,11 @@ bidi-class produces one table of Unicode codepoint ranges for each
 possible Bidi_Class value.
 ";
 
+const ABOUT_EAST_ASIAN_WIDTH: &'static str = "\
+east-asian-width produces one table of Unicode codepoint ranges for each
+possible East_Asian_Width value.
+";
+
 const ABOUT_CANONICAL_COMBINING_CLASS: &'static str = "\
 canonical-combining-class produces one table of Unicode codepoint ranges for
 each possible Canonical_Combining_Class value.
@@ -57,6 +62,12 @@ script produces one table of Unicode codepoint ranges for each possible Script
 value.
 ";
 
+const ABOUT_BLOCK: &'static str = "\
+block produces one table of Unicode codepoint ranges for each possible Block
+value. Codepoints that are not in any table have the value No_Block, which is
+not emitted in any output mode (including --enum and --rust-enum).
+";
+
 const ABOUT_SCRIPT_EXTENSION: &'static str = "\
 script-extension produces one table of Unicode codepoint ranges for each
 possible Script_Extension value.
@@ -72,6 +83,33 @@ joining-type produces one table of Unicode codepoint ranges for each
 possible Joining_Type value.
 ";
 
+const ABOUT_NUMERIC: &'static str = "\
+numeric produces a table that maps codepoints to their Numeric_Type and a table
+that maps codepoints to their Numeric_Value. Each Numeric_Value is written as a
+rational number, given as a numerator and a denominator. Codepoints that are
+absent from both tables have a Numeric_Type of None.
+
+When --decimal-only is given, a single table is emitted instead, which maps
+each codepoint with Numeric_Type=Decimal to its digit value.
+";
+
+const ABOUT_INDIC: &'static str = "\
+indic produces one table of Unicode codepoint ranges for each possible
+Indic_Syllabic_Category value. With --positional, the tables are produced for
+the Indic_Positional_Category property instead.
+";
+
+const ABOUT_VERTICAL_ORIENTATION: &'static str = "\
+vertical-orientation produces one table of Unicode codepoint ranges for each
+possible Vertical_Orientation value. Codepoints that are not listed in
+VerticalOrientation.txt get the default value given by its @missing line.
+";
+
+const ABOUT_HANGUL_SYLLABLE_TYPE: &'static str = "\
+hangul-syllable-type produces one table of Unicode codepoint ranges for each
+possible Hangul_Syllable_Type value.
+";
+
 const ABOUT_AGE: &'static str = "\
 age produces a table for each discrete Unicode age. Each table includes the
 codepoints that were added for that age. Tables can be emitted as a sorted
@@ -84,11 +122,66 @@ Bidi_Mirrored=Yes property to another codepoint that typically has a glyph that
 is the mirror image of the original codepoint's glyph.
 ";
 
+const ABOUT_BIDI_BRACKETS: &'static str = "\
+bidi-brackets produces a table that maps each codepoint with a
+Bidi_Paired_Bracket_Type of Open or Close to its paired bracket and its bracket
+type, as required by rule N0 of the Unicode Bidirectional Algorithm.
+
+With --canonical, an additional table is emitted that maps each bracket with a
+canonical singleton decomposition to its canonically equivalent bracket. For
+example, U+2329 maps to U+3008. This permits pairing canonically equivalent
+brackets without access to normalization data.
+";
+
 const ABOUT_PROP_BOOL: &'static str = "\
 property-bool produces possibly many tables for boolean properties. Tables can
 be emitted as a sorted sequence of ranges, an FST or a trie.
 ";
 
+const ABOUT_EMOJI_SEQUENCES: &'static str = "\
+emoji-sequences produces one sorted table of strings for each emoji property of
+strings defined in emoji-sequences.txt and emoji-zwj-sequences.txt, such as
+Basic_Emoji and RGI_Emoji_ZWJ_Sequence. A table for RGI_Emoji, which is the
+union of all of them, is also produced. When emitting an FST, each table is a
+set of the UTF-8 encodings of its strings.
+";
+
+const ABOUT_VARIATION_SEQUENCES: &'static str = "\
+variation-sequences produces a sorted table of all valid variation sequences,
+where each entry is a pair of a base codepoint and a variation selector. The
+sequences are taken from StandardizedVariants.txt and, unless --no-emoji is
+given, emoji-variation-sequences.txt.
+
+Each entry can optionally include a description of the sequence (e.g., 'text
+style' or 'emoji style' for emoji, or the described glyph variant otherwise)
+and the shaping environments in which the sequence applies.
+";
+
+const ABOUT_UNIHAN: &'static str = "\
+unihan produces a table that maps codepoints to the value of a single field in
+the Unihan database, such as kDefinition or kMandarin. Values are emitted as
+they appear in the Unihan_*.txt files, which are looked for in the UCD
+directory and in a Unihan sub-directory of it.
+
+When emitting an FST, the FST maps codepoints to indices into a separate
+_STRINGS table, which holds each distinct value once.
+";
+
+const ABOUT_RADICAL_STROKE: &'static str = "\
+radical-stroke produces tables for looking up CJK ideographs by radical and
+stroke count, from the kRSUnicode and kTotalStrokes fields of the Unihan
+database and from CJKRadicals.txt.
+
+The main table maps codepoints to their first kRSUnicode radical-stroke count,
+packed into an integer. Bits 16-23 are the radical number, bits 8-15 are the
+number of apostrophes following it (1 indicates a simplified radical) and bits
+0-7 are the residual strokes as a signed 8-bit integer. The _TOTAL_STROKES
+table maps codepoints to their total stroke count. The _RADICALS table maps
+radical numbers (such as 90') to their CJK unified ideograph. The _INDEX table
+maps radical numbers to all codepoints counted under that radical, in order of
+residual strokes.
+";
+
 const ABOUT_PERL_WORD: &'static str = "\
 perl-word emits a table of codepoints in Unicode's definition of the \\w
 character class, according to Annex C in UTS#18. In particular, this includes
@@ -121,6 +214,35 @@ names that are algorithmically generated such as Hangul syllables and
 ideographs. Flags can be provided to tweak this behavior.
 
 This table maps character names to codepoints.
+
+With --reverse, a table that maps codepoints to names is emitted instead. Each
+codepoint maps to its name from UnicodeData.txt, unless it has an alias with
+one of the labels given to --prefer-alias. Algorithmically generated names are
+not expanded. Instead, the codepoint ranges given by the <..., First> and
+<..., Last> records in UnicodeData.txt are emitted in a separate _ALGORITHMIC
+table as (start, end, prefix) triples. For ideographs, the name is the prefix
+followed by the codepoint in hexadecimal. For Hangul syllables, it is the
+prefix followed by the short names of the syllable's jamo.
+
+With --compressed, names are instead stored as sequences of indices into a
+shared dictionary of the words that occur in names, where more common words
+have shorter indices. The table supports looking up both the name of a
+codepoint and the codepoint of a name, through the emitted _lookup_name and
+_lookup_codepoint functions. The former reconstructs the name into a buffer
+provided by the caller. As with --reverse, algorithmically generated names are
+emitted as ranges in a separate _ALGORITHMIC table, which both functions fall
+back to for codepoints and names that are not in the compressed table.
+
+With --named-sequences, a second table is emitted that maps the names of named
+character sequences (from NamedSequences.txt) to their sequence of codepoints.
+";
+
+const ABOUT_NAMES_LIST: &'static str = "\
+names-list emits tables derived from the annotations in NamesList.txt. The
+_ALIASES table maps codepoints to their informative aliases, and the
+_CROSS_REFERENCES table maps codepoints to the codepoints they are
+cross-referenced with. Both tables list values in the order in which they
+appear in NamesList.txt.
 ";
 
 const ABOUT_TEST_UNICODE_DATA: &'static str = "\
@@ -145,14 +267,67 @@ case-folding emits a table of Simple case folding mappings from codepoint
 to codepoint. When codepoints are mapped according to this table, then case
 differences (according to Unicode) are eliminated.
 ";
+const ABOUT_CASE_FOLDING_FULL: &'static str = "\
+case-folding-full emits a table of Full case folding mappings from codepoint
+to a sequence of codepoints. That is, the mappings with status C and F in
+CaseFolding.txt. This is the case folding used for caseless matching, as
+described in Unicode section 3.13.
+";
 const ABOUT_CASE_MAPPING: &'static str = "\
 case-mapping emits case mapping tables, which map from a codepoint to a
 list of codepoints (currently up to three), and are used to convert
 text between lower, upper, and title cases.
 
-This command currently has no support for emitting the conditional case
-mapping data, and can only produce the unconditional mapping tables.
+By default, only the unconditional mapping tables are emitted. With
+--conditional, the language and context sensitive mappings from
+SpecialCasing.txt are also emitted as separate tables. Each entry in these
+tables is a codepoint, a list of conditions (all of which must hold) and a
+mapping. Conditions that are not recognized are emitted as Other, with the
+condition as it appears in SpecialCasing.txt.
+";
+const ABOUT_COMPOSITION: &'static str = "\
+composition emits a table of canonical composition pairs, mapping a starter
+and a following combining codepoint to their primary composite, as needed by
+the NFC and NFKC normalization forms.
+
+The pairs are derived from the canonical decomposition mappings in
+UnicodeData.txt, excluding singletons, non-starter decompositions and the
+codepoints listed in CompositionExclusions.txt. Hangul syllables are not
+included, since their composition is algorithmic.
+
+When emitting an FST, each key is the starter followed by the combining
+codepoint, both encoded as big endian u32s.
+";
+const ABOUT_DECOMPOSITION: &'static str = "\
+decomposition emits a table of decomposition mappings from codepoint to a
+sequence of codepoints. By default, only canonical decomposition mappings are
+emitted. Compatibility mappings can be included with --compat, and mappings can
+be fully expanded with --recursive (as required by the NFD and NFKD
+normalization forms).
+
+Decomposition mappings for Hangul syllables are included by default, even
+though they are not listed in UnicodeData.txt.
 ";
+
+const ABOUT_NORMALIZATION_QUICK_CHECK: &'static str = "\
+normalization-quick-check produces one table of Unicode codepoint ranges for
+each possible value (Yes, No or Maybe) of one of the NFC_QC, NFD_QC, NFKC_QC
+or NFKD_QC properties. The normalization form is selected with --form.
+Codepoints not listed in DerivedNormalizationProps.txt are assigned the default
+value given by the @missing line for that property.
+";
+
+const ABOUT_NFKC_CASEFOLD: &'static str = "\
+nfkc-casefold emits a table of NFKC_Casefold (NFKC_CF) mappings from codepoint
+to a possibly empty sequence of codepoints. Codepoints that are not in the
+table map to themselves. With --simple, the NFKC_Simple_Casefold (NFKC_SCF)
+mappings are emitted instead, which are only available in Unicode 15.1 and
+newer.
+
+When --flat-table is used, an empty mapping is represented by an array that
+only contains padding.
+";
+
 const ABOUT_GRAPHEME_CLUSTER_BREAK: &'static str = "\
 grapheme-cluster-break emits the table of property values and their
 corresponding codepoints for the Grapheme_Cluster_Break property.
@@ -168,6 +343,12 @@ sentence-break emits the table of property values and their corresponding
 codepoints for the Sentence_Break property.
 ";
 
+const ABOUT_LINE_BREAK: &'static str = "\
+line-break emits the table of property values and their corresponding
+codepoints for the Line_Break property. Codepoints not listed in LineBreak.txt
+are assigned the default value given by the @missing lines in that file.
+";
+
 const ABOUT_DFA: &'static str = "\
 dfa emits a single serialized DFAs from an arbitrary regular expression. If
 you want a regular expression for finding the start and end of a match, then
@@ -215,11 +396,16 @@ pub fn app() -> App<'static, 'static> {
     let flag_flat_table =
         Arg::with_name("flat-table").long("flat-table").help(
             "When emitting a map of a single codepoint to multiple \
-             codepoints, emit entries as `(u32, [u32; 3])` instead of as \
+             codepoints, emit entries as `(u32, [u32; N])` instead of as \
              `(u32, &[u32])` (replacing `u32` with `char` if `--chars` is \
-             passed). Conceptually unoccupied indices of the array will \
+             passed), where `N` is the length of the longest mapping in \
+             the table. Conceptually unoccupied indices of the array will \
              contain `!0u32` (for u32) or `\\u{0}` (for `char`).",
         );
+    let flag_turkic = Arg::with_name("turkic").long("turkic").help(
+        "Use the Turkic mappings (status T) in place of the normal mappings \
+         for the dotted and dotless I, as used by Turkish and Azerbaijani.",
+    );
     let ucd_dir = Arg::with_name("ucd-dir")
         .required(true)
         .help("Directory containing the Unicode character database files.");
@@ -265,6 +451,23 @@ pub fn app() -> App<'static, 'static> {
             .arg(Arg::with_name("rust-match").long("rust-match").help(
                 "Emit a function that uses a match to map between codepoints.",
             ));
+    let cmd_bidi_brackets = SubCommand::with_name("bidi-brackets")
+        .author(clap::crate_authors!())
+        .version(clap::crate_version!())
+        .template(TEMPLATE_SUB)
+        .about("Create Unicode Bidi Paired Bracket table.")
+        .before_help(ABOUT_BIDI_BRACKETS)
+        .arg(ucd_dir.clone())
+        .arg(flag_name("BIDI_BRACKETS"))
+        .arg(flag_chars.clone())
+        .arg(Arg::with_name("canonical").long("canonical").help(
+            "Also emit a table that maps brackets to their canonically \
+             equivalent bracket, using the canonical singleton \
+             decompositions in UnicodeData.txt.",
+        ))
+        .arg(Arg::with_name("rust-match").long("rust-match").help(
+            "Emit a function that uses a match to map between codepoints.",
+        ));
     let cmd_canonical_combining_class =
         SubCommand::with_name("canonical-combining-class")
             .author(clap::crate_authors!())
@@ -289,6 +492,38 @@ pub fn app() -> App<'static, 'static> {
                 "List all of the canonical combining class names with \
                  abbreviations.",
             ));
+    let cmd_east_asian_width = SubCommand::with_name("east-asian-width")
+        .author(clap::crate_authors!())
+        .version(clap::crate_version!())
+        .template(TEMPLATE_SUB)
+        .about("Create the East_Asian_Width property tables.")
+        .before_help(ABOUT_EAST_ASIAN_WIDTH)
+        .arg(ucd_dir.clone())
+        .arg(flag_fst_dir.clone())
+        .arg(flag_name("EAST_ASIAN_WIDTH"))
+        .arg(flag_chars.clone())
+        .arg(flag_trie_set.clone())
+        .arg(flag_short_names.clone())
+        .arg(flag_combined.clone())
+        .arg(Arg::with_name("enum").long("enum").help(
+            "Emit a single table that maps codepoints to East Asian width.",
+        ))
+        .arg(Arg::with_name("rust-enum").long("rust-enum").help(
+            "Emit a Rust enum and a table that maps codepoints to East \
+             Asian width.",
+        ))
+        .arg(Arg::with_name("include").long("include").takes_value(true).help(
+            "A comma separated list of East Asian widths to include. \
+             When absent, all widths are included.",
+        ))
+        .arg(Arg::with_name("exclude").long("exclude").takes_value(true).help(
+            "A comma separated list of East Asian widths to exclude. \
+             When absent, no widths are excluded. This overrides widths \
+             specified with the --include flag.",
+        ))
+        .arg(Arg::with_name("list-values").long("list-values").help(
+            "List all of the East Asian width names with abbreviations.",
+        ));
     let cmd_general_category = SubCommand::with_name("general-category")
         .author(clap::crate_authors!())
         .version(clap::crate_version!())
@@ -357,6 +592,40 @@ pub fn app() -> App<'static, 'static> {
                 .long("list-scripts")
                 .help("List all of the script names with abbreviations."),
         );
+    let cmd_block = SubCommand::with_name("block")
+        .author(clap::crate_authors!())
+        .version(clap::crate_version!())
+        .template(TEMPLATE_SUB)
+        .about("Create the Block property tables.")
+        .before_help(ABOUT_BLOCK)
+        .arg(ucd_dir.clone())
+        .arg(flag_fst_dir.clone())
+        .arg(flag_name("BLOCK"))
+        .arg(flag_chars.clone())
+        .arg(flag_trie_set.clone())
+        .arg(flag_combined.clone())
+        .arg(
+            Arg::with_name("enum")
+                .long("enum")
+                .help("Emit a single table that maps codepoints to blocks."),
+        )
+        .arg(Arg::with_name("rust-enum").long("rust-enum").help(
+            "Emit a Rust enum and a table that maps codepoints to blocks.",
+        ))
+        .arg(Arg::with_name("include").long("include").takes_value(true).help(
+            "A comma separated list of blocks to include. \
+             When absent, all blocks are included.",
+        ))
+        .arg(Arg::with_name("exclude").long("exclude").takes_value(true).help(
+            "A comma separated list of blocks to exclude. \
+             When absent, no blocks are excluded. This overrides \
+             blocks specified with the --include flag.",
+        ))
+        .arg(
+            Arg::with_name("list-blocks")
+                .long("list-blocks")
+                .help("List all of the block names with abbreviations."),
+        );
     let cmd_script_extension = SubCommand::with_name("script-extension")
         .author(clap::crate_authors!())
         .version(clap::crate_version!())
@@ -438,6 +707,103 @@ pub fn app() -> App<'static, 'static> {
                 "Emit a Rust enum and a table that maps codepoints to \
                  joining type.",
             ));
+    let cmd_numeric = SubCommand::with_name("numeric")
+        .author(clap::crate_authors!())
+        .version(clap::crate_version!())
+        .template(TEMPLATE_SUB)
+        .about("Create the Numeric_Type and Numeric_Value tables.")
+        .before_help(ABOUT_NUMERIC)
+        .arg(ucd_dir.clone())
+        .arg(flag_fst_dir.clone().requires("decimal-only"))
+        .arg(flag_name("NUMERIC"))
+        .arg(flag_chars.clone())
+        .arg(Arg::with_name("rust-enum").long("rust-enum").help(
+            "Emit a Rust enum and a table that maps codepoints to numeric \
+             type.",
+        ))
+        .arg(
+            Arg::with_name("decimal-only")
+                .long("decimal-only")
+                .conflicts_with("rust-enum")
+                .help(
+                    "Only emit a table that maps decimal digits to their \
+                     value.",
+                ),
+        );
+    let cmd_indic = SubCommand::with_name("indic")
+        .author(clap::crate_authors!())
+        .version(clap::crate_version!())
+        .template(TEMPLATE_SUB)
+        .about(
+            "Create the Indic_Syllabic_Category or \
+             Indic_Positional_Category property tables.",
+        )
+        .before_help(ABOUT_INDIC)
+        .arg(ucd_dir.clone())
+        .arg(flag_fst_dir.clone())
+        .arg(
+            Arg::with_name("name")
+                .long("name")
+                .help(
+                    "Set the name of the table in the emitted code. \
+                     Defaults to the name of the property.",
+                )
+                .takes_value(true),
+        )
+        .arg(flag_chars.clone())
+        .arg(flag_trie_set.clone())
+        .arg(Arg::with_name("positional").long("positional").help(
+            "Emit the Indic_Positional_Category property instead of the \
+             Indic_Syllabic_Category property.",
+        ))
+        .arg(
+            Arg::with_name("enum").long("enum").help(
+                "Emit a single table that maps codepoints to categories.",
+            ),
+        )
+        .arg(Arg::with_name("rust-enum").long("rust-enum").help(
+            "Emit a Rust enum and a table that maps codepoints to categories.",
+        ));
+    let cmd_vertical_orientation =
+        SubCommand::with_name("vertical-orientation")
+            .author(clap::crate_authors!())
+            .version(clap::crate_version!())
+            .template(TEMPLATE_SUB)
+            .about("Create the Vertical_Orientation property tables.")
+            .before_help(ABOUT_VERTICAL_ORIENTATION)
+            .arg(ucd_dir.clone())
+            .arg(flag_fst_dir.clone())
+            .arg(flag_name("VERTICAL_ORIENTATION"))
+            .arg(flag_chars.clone())
+            .arg(flag_trie_set.clone())
+            .arg(Arg::with_name("enum").long("enum").help(
+                "Emit a single table that maps codepoints to vertical \
+                 orientation.",
+            ))
+            .arg(Arg::with_name("rust-enum").long("rust-enum").help(
+                "Emit a Rust enum and a table that maps codepoints to \
+                 vertical orientation.",
+            ));
+    let cmd_hangul_syllable_type =
+        SubCommand::with_name("hangul-syllable-type")
+            .author(clap::crate_authors!())
+            .version(clap::crate_version!())
+            .template(TEMPLATE_SUB)
+            .about("Create the Hangul_Syllable_Type property tables.")
+            .before_help(ABOUT_HANGUL_SYLLABLE_TYPE)
+            .arg(ucd_dir.clone())
+            .arg(flag_fst_dir.clone())
+            .arg(flag_name("HANGUL_SYLLABLE_TYPE"))
+            .arg(flag_chars.clone())
+            .arg(flag_trie_set.clone())
+            .arg(Arg::with_name("enum").long("enum").help(
+                "Emit a single table that maps codepoints to Hangul \
+                 syllable types.",
+            ))
+            .arg(Arg::with_name("rust-enum").long("rust-enum").help(
+                "Emit a Rust enum and a table that maps codepoints to \
+                 Hangul syllable types.",
+            ));
     let cmd_prop_bool = SubCommand::with_name("property-bool")
         .author(clap::crate_authors!())
         .version(clap::crate_version!())
@@ -461,6 +827,95 @@ pub fn app() -> App<'static, 'static> {
             "List the properties that can be generated with this \
              command.",
         ));
+    let cmd_emoji_sequences = SubCommand::with_name("emoji-sequences")
+        .author(clap::crate_authors!())
+        .version(clap::crate_version!())
+        .template(TEMPLATE_SUB)
+        .about("Create emoji property of strings tables.")
+        .before_help(ABOUT_EMOJI_SEQUENCES)
+        .arg(ucd_dir.clone())
+        .arg(flag_fst_dir.clone())
+        .arg(Arg::with_name("include").long("include").takes_value(true).help(
+            "A comma separated list of properties to include. \
+             When absent, all available properties are included.",
+        ))
+        .arg(Arg::with_name("exclude").long("exclude").takes_value(true).help(
+            "A comma separated list of properties to exclude. \
+             When absent, no properties are excluded. This overrides \
+             properties specified with the --include flag.",
+        ))
+        .arg(Arg::with_name("list-properties").long("list-properties").help(
+            "List the properties that can be generated with this \
+             command.",
+        ));
+    let cmd_variation_sequences =
+        SubCommand::with_name("variation-sequences")
+            .author(clap::crate_authors!())
+            .version(clap::crate_version!())
+            .template(TEMPLATE_SUB)
+            .about("Create a table of valid variation sequences.")
+            .before_help(ABOUT_VARIATION_SEQUENCES)
+            .arg(ucd_dir.clone())
+            .arg(
+                flag_fst_dir
+                    .clone()
+                    .conflicts_with("descriptions")
+                    .conflicts_with("environments"),
+            )
+            .arg(flag_name("VARIATION_SEQUENCES"))
+            .arg(flag_chars.clone())
+            .arg(Arg::with_name("no-emoji").long("no-emoji").help(
+                "Do not include the emoji variation sequences from \
+                 emoji-variation-sequences.txt.",
+            ))
+            .arg(Arg::with_name("descriptions").long("descriptions").help(
+                "Include the description of each sequence in the table.",
+            ))
+            .arg(Arg::with_name("environments").long("environments").help(
+                "Include the shaping environments in which each sequence \
+                 applies in the table. An empty list means that the \
+                 sequence applies in all shaping environments.",
+            ));
+    let cmd_unihan = SubCommand::with_name("unihan")
+        .author(clap::crate_authors!())
+        .version(clap::crate_version!())
+        .template(TEMPLATE_SUB)
+        .about("Create a table for a field of the Unihan database.")
+        .before_help(ABOUT_UNIHAN)
+        .arg(ucd_dir.clone())
+        .arg(flag_fst_dir.clone())
+        .arg(flag_chars.clone())
+        .arg(
+            Arg::with_name("name")
+                .long("name")
+                .help(
+                    "Set the name of the table in the emitted code. \
+                     Defaults to the name of the field without its 'k' \
+                     prefix, e.g., TOTAL_STROKES for kTotalStrokes.",
+                )
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("field")
+                .long("field")
+                .takes_value(true)
+                .required_unless("list-fields")
+                .help("The Unihan field to emit, e.g., kDefinition."),
+        )
+        .arg(Arg::with_name("list-fields").long("list-fields").help(
+            "List the Unihan fields that can be generated with this \
+             command.",
+        ));
+    let cmd_radical_stroke = SubCommand::with_name("radical-stroke")
+        .author(clap::crate_authors!())
+        .version(clap::crate_version!())
+        .template(TEMPLATE_SUB)
+        .about("Create CJK radical-stroke tables.")
+        .before_help(ABOUT_RADICAL_STROKE)
+        .arg(ucd_dir.clone())
+        .arg(flag_fst_dir.clone())
+        .arg(flag_chars.clone())
+        .arg(flag_name("RADICAL_STROKE"));
     let cmd_perl_word = SubCommand::with_name("perl-word")
         .author(clap::crate_authors!())
         .version(clap::crate_version!())
@@ -482,40 +937,114 @@ pub fn app() -> App<'static, 'static> {
         .arg(flag_fst_dir.clone())
         .arg(flag_chars.clone())
         .arg(flag_name("JAMO_SHORT_NAME"));
-    let cmd_names =
-        SubCommand::with_name("names")
-            .author(clap::crate_authors!())
-            .version(clap::crate_version!())
-            .template(TEMPLATE_SUB)
-            .about("Create a mapping from character name to codepoint.")
-            .before_help(ABOUT_NAMES)
-            .arg(ucd_dir.clone())
-            .arg(flag_fst_dir.clone())
-            .arg(flag_chars.clone().conflicts_with("tagged"))
-            .arg(flag_name("NAMES"))
-            .arg(Arg::with_name("no-aliases").long("no-aliases").help(
-                "Ignore all character name aliases. When used, every name \
-                 maps to exactly one codepoint.",
-            ))
-            .arg(Arg::with_name("no-ideograph").long("no-ideograph").help(
+    let cmd_names = SubCommand::with_name("names")
+        .author(clap::crate_authors!())
+        .version(clap::crate_version!())
+        .template(TEMPLATE_SUB)
+        .about("Create a mapping from character name to codepoint.")
+        .before_help(ABOUT_NAMES)
+        .arg(ucd_dir.clone())
+        .arg(flag_fst_dir.clone())
+        .arg(flag_chars.clone().conflicts_with("tagged"))
+        .arg(flag_name("NAMES"))
+        .arg(Arg::with_name("no-aliases").long("no-aliases").help(
+            "Ignore all character name aliases. When used, every name \
+             maps to exactly one codepoint.",
+        ))
+        .arg(
+            Arg::with_name("alias-labels")
+                .long("alias-labels")
+                .takes_value(true)
+                .conflicts_with("no-aliases")
+                .help(
+                    "A comma separated list of alias labels (correction, \
+                     control, alternate, figment or abbreviation). Only \
+                     aliases with one of these labels are included.",
+                ),
+        )
+        .arg(
+            Arg::with_name("no-ideograph").long("no-ideograph").help(
                 "Do not include algorithmically generated ideograph names.",
-            ))
-            .arg(Arg::with_name("no-hangul").long("no-hangul").help(
-                "Do not include algorithmically generated Hangul syllable \
-                 names.",
-            ))
-            .arg(Arg::with_name("tagged").long("tagged").help(
-                "Tag each codepoint with how the name was derived. \
-                 The lower 32 bits corresponds to the codepoint. Bit 33 \
-                 indicates the name was explicitly provided in \
-                 UnicodeData.txt. Bit 34 indicates the name is from \
-                 NameAliases.txt. \
-                 Bit 35 indicates the name is a Hangul syllable. Bit 36 \
-                 indicates the name is an ideograph.",
-            ))
-            .arg(Arg::with_name("normalize").long("normalize").help(
-                "Normalize all character names according to UAX44-LM2.",
-            ));
+            ),
+        )
+        .arg(Arg::with_name("no-hangul").long("no-hangul").help(
+            "Do not include algorithmically generated Hangul syllable \
+             names.",
+        ))
+        .arg(Arg::with_name("tagged").long("tagged").help(
+            "Tag each codepoint with how the name was derived. \
+             The lower 32 bits corresponds to the codepoint. Bit 33 \
+             indicates the name was explicitly provided in \
+             UnicodeData.txt. Bit 34 indicates the name is from \
+             NameAliases.txt. \
+             Bit 35 indicates the name is a Hangul syllable. Bit 36 \
+             indicates the name is an ideograph. Bits 37 through 41 \
+             indicate that the name is an alias with the correction, \
+             control, alternate, figment or abbreviation label, \
+             respectively.",
+        ))
+        .arg(
+            Arg::with_name("normalize")
+                .long("normalize")
+                .help("Normalize all character names according to UAX44-LM2."),
+        )
+        .arg(Arg::with_name("named-sequences").long("named-sequences").help(
+            "Also emit a table that maps the names of named character \
+             sequences to their codepoints. When emitting an FST, the FST \
+             maps each name to an index into a separate table of codepoint \
+             sequences.",
+        ))
+        .arg(
+            Arg::with_name("provisional")
+                .long("provisional")
+                .requires("named-sequences")
+                .help(
+                    "Include the provisional named sequences from \
+                     NamedSequencesProv.txt.",
+                ),
+        )
+        .arg(
+            Arg::with_name("compressed")
+                .long("compressed")
+                .conflicts_with("fst-dir")
+                .conflicts_with("chars")
+                .conflicts_with("tagged")
+                .conflicts_with("reverse")
+                .conflicts_with("normalize")
+                .help(
+                    "Emit a compressed table of names that supports lookups \
+                     in both directions, along with accessor functions.",
+                ),
+        )
+        .arg(
+            Arg::with_name("reverse")
+                .long("reverse")
+                .conflicts_with("tagged")
+                .conflicts_with("named-sequences")
+                .help("Emit a map from codepoint to name instead."),
+        )
+        .arg(
+            Arg::with_name("prefer-alias")
+                .long("prefer-alias")
+                .takes_value(true)
+                .requires("reverse")
+                .conflicts_with("no-aliases")
+                .help(
+                    "A comma separated list of alias labels (correction, \
+                     control, alternate, figment or abbreviation), in order \
+                     of preference. When a codepoint has an alias with one \
+                     of these labels, it is used as its name instead.",
+                ),
+        );
+    let cmd_names_list = SubCommand::with_name("names-list")
+        .author(clap::crate_authors!())
+        .version(clap::crate_version!())
+        .template(TEMPLATE_SUB)
+        .about("Create alias and cross reference tables from NamesList.txt.")
+        .before_help(ABOUT_NAMES_LIST)
+        .arg(ucd_dir.clone())
+        .arg(flag_chars.clone())
+        .arg(flag_name("NAMES_LIST"));
     let cmd_property_names = SubCommand::with_name("property-names")
         .author(clap::crate_authors!())
         .version(clap::crate_version!())
@@ -567,6 +1096,7 @@ pub fn app() -> App<'static, 'static> {
         .arg(ucd_dir.clone())
         .arg(flag_fst_dir.clone())
         .arg(flag_chars.clone())
+        .arg(flag_turkic.clone())
         .arg(
             Arg::with_name("circular")
                 .long("circular")
@@ -581,6 +1111,17 @@ pub fn app() -> App<'static, 'static> {
             Ignored when all-pairs is specified.",
         ))
         .arg(flag_flat_table.clone().requires("all-pairs"));
+    let cmd_case_folding_full = SubCommand::with_name("case-folding-full")
+        .author(clap::crate_authors!())
+        .version(clap::crate_version!())
+        .template(TEMPLATE_SUB)
+        .about("Create a case folding table using the full mapping.")
+        .before_help(ABOUT_CASE_FOLDING_FULL)
+        .arg(flag_name("CASE_FOLDING_FULL"))
+        .arg(ucd_dir.clone())
+        .arg(flag_chars.clone())
+        .arg(flag_turkic.clone())
+        .arg(flag_flat_table.clone());
     let cmd_case_mapping = SubCommand::with_name("case-mapping")
         .author(clap::crate_authors!())
         .version(clap::crate_version!())
@@ -598,7 +1139,94 @@ pub fn app() -> App<'static, 'static> {
              (emit maps of codepoint to codepoint, \
              ignoring rules from SpecialCasing.txt)",
         ))
-        .arg(flag_flat_table.clone().conflicts_with("simple"));
+        .arg(flag_flat_table.clone().conflicts_with("simple"))
+        .arg(
+            Arg::with_name("conditional")
+                .long("conditional")
+                .conflicts_with("simple")
+                .help(
+                    "Also emit tables of the conditional mappings from \
+                     SpecialCasing.txt.",
+                ),
+        );
+    let cmd_composition = SubCommand::with_name("composition")
+        .author(clap::crate_authors!())
+        .version(clap::crate_version!())
+        .template(TEMPLATE_SUB)
+        .about("Create a table of canonical composition pairs.")
+        .before_help(ABOUT_COMPOSITION)
+        .arg(flag_name("COMPOSITION"))
+        .arg(ucd_dir.clone())
+        .arg(flag_fst_dir.clone())
+        .arg(flag_chars.clone())
+        .arg(Arg::with_name("rust-match").long("rust-match").help(
+            "Emit a function that uses a match to map pairs of codepoints \
+             to their composite.",
+        ));
+    let cmd_decomposition = SubCommand::with_name("decomposition")
+        .author(clap::crate_authors!())
+        .version(clap::crate_version!())
+        .template(TEMPLATE_SUB)
+        .about("Create a table of decomposition mappings.")
+        .before_help(ABOUT_DECOMPOSITION)
+        .arg(flag_name("DECOMPOSITION"))
+        .arg(ucd_dir.clone())
+        .arg(flag_chars.clone())
+        .arg(Arg::with_name("compat").long("compat").help(
+            "Include compatibility decomposition mappings in addition to \
+             canonical decomposition mappings.",
+        ))
+        .arg(Arg::with_name("recursive").long("recursive").help(
+            "Recursively apply decomposition mappings, such that each \
+             codepoint maps to its full decomposition.",
+        ))
+        .arg(Arg::with_name("no-hangul").long("no-hangul").help(
+            "Do not include the algorithmic decompositions of Hangul \
+             syllables.",
+        ))
+        .arg(flag_flat_table.clone());
+    let cmd_normalization_quick_check =
+        SubCommand::with_name("normalization-quick-check")
+            .author(clap::crate_authors!())
+            .version(clap::crate_version!())
+            .template(TEMPLATE_SUB)
+            .about("Create the normalization quick check property tables.")
+            .before_help(ABOUT_NORMALIZATION_QUICK_CHECK)
+            .arg(ucd_dir.clone())
+            .arg(flag_fst_dir.clone())
+            .arg(flag_name("NORMALIZATION_QUICK_CHECK"))
+            .arg(flag_chars.clone())
+            .arg(flag_trie_set.clone())
+            .arg(
+                Arg::with_name("form")
+                    .long("form")
+                    .help("The normalization form to emit tables for.")
+                    .takes_value(true)
+                    .possible_values(&["NFC", "NFD", "NFKC", "NFKD"])
+                    .default_value("NFC"),
+            )
+            .arg(Arg::with_name("enum").long("enum").help(
+                "Emit a single table that maps codepoints to quick check \
+                 values.",
+            ))
+            .arg(Arg::with_name("rust-enum").long("rust-enum").help(
+                "Emit a Rust enum and a table that maps codepoints to quick \
+                 check values.",
+            ));
+    let cmd_nfkc_casefold = SubCommand::with_name("nfkc-casefold")
+        .author(clap::crate_authors!())
+        .version(clap::crate_version!())
+        .template(TEMPLATE_SUB)
+        .about("Create a table of NFKC_Casefold mappings.")
+        .before_help(ABOUT_NFKC_CASEFOLD)
+        .arg(flag_name("NFKC_CASEFOLD"))
+        .arg(ucd_dir.clone())
+        .arg(flag_chars.clone())
+        .arg(Arg::with_name("simple").long("simple").help(
+            "Emit the NFKC_Simple_Casefold mappings instead of the \
+             NFKC_Casefold mappings.",
+        ))
+        .arg(flag_flat_table.clone());
 
     let cmd_grapheme_cluster_break =
         SubCommand::with_name("grapheme-cluster-break")
@@ -649,6 +1277,25 @@ pub fn app() -> App<'static, 'static> {
                 .long("enum")
                 .help("Emit a single table that maps codepoints to values."),
         );
+    let cmd_line_break = SubCommand::with_name("line-break")
+        .author(clap::crate_authors!())
+        .version(clap::crate_version!())
+        .template(TEMPLATE_SUB)
+        .about("Create a table for each Line_Break value.")
+        .before_help(ABOUT_LINE_BREAK)
+        .arg(flag_name("LINE_BREAK"))
+        .arg(ucd_dir.clone())
+        .arg(flag_fst_dir.clone())
+        .arg(flag_chars.clone())
+        .arg(flag_trie_set.clone())
+        .arg(
+            Arg::with_name("enum")
+                .long("enum")
+                .help("Emit a single table that maps codepoints to values."),
+        )
+        .arg(Arg::with_name("rust-enum").long("rust-enum").help(
+            "Emit a Rust enum and a table that maps codepoints to values.",
+        ));
     let cmd_dfa = SubCommand::with_name("dfa")
         .author(clap::crate_authors!())
         .version(clap::crate_version!())
@@ -712,24 +1359,42 @@ pub fn app() -> App<'static, 'static> {
         .setting(AppSettings::UnifiedHelpMessage)
         .subcommand(cmd_bidi_class)
         .subcommand(cmd_canonical_combining_class)
+        .subcommand(cmd_east_asian_width)
         .subcommand(cmd_general_category)
         .subcommand(cmd_script)
         .subcommand(cmd_script_extension)
+        .subcommand(cmd_block)
         .subcommand(cmd_joining_group)
         .subcommand(cmd_joining_type)
+        .subcommand(cmd_numeric)
+        .subcommand(cmd_indic)
+        .subcommand(cmd_vertical_orientation)
+        .subcommand(cmd_hangul_syllable_type)
         .subcommand(cmd_age)
         .subcommand(cmd_bidi_mirroring_glyph)
+        .subcommand(cmd_bidi_brackets)
         .subcommand(cmd_prop_bool)
+        .subcommand(cmd_emoji_sequences)
+        .subcommand(cmd_variation_sequences)
+        .subcommand(cmd_unihan)
+        .subcommand(cmd_radical_stroke)
         .subcommand(cmd_perl_word)
         .subcommand(cmd_jamo_short_name)
         .subcommand(cmd_names)
+        .subcommand(cmd_names_list)
         .subcommand(cmd_property_names)
         .subcommand(cmd_property_values)
         .subcommand(cmd_case_folding_simple)
+        .subcommand(cmd_case_folding_full)
         .subcommand(cmd_case_mapping)
+        .subcommand(cmd_composition)
+        .subcommand(cmd_decomposition)
+        .subcommand(cmd_normalization_quick_check)
+        .subcommand(cmd_nfkc_casefold)
         .subcommand(cmd_grapheme_cluster_break)
         .subcommand(cmd_word_break)
         .subcommand(cmd_sentence_break)
+        .subcommand(cmd_line_break)
         .subcommand(cmd_dfa)
         .subcommand(cmd_regex)
         .subcommand(cmd_test_unicode_data)
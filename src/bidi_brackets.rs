@@ -0,0 +1,69 @@
+use std::collections::BTreeMap;
+
+use ucd_parse::{self, BidiBracket, UnicodeData};
+
+use crate::args::ArgMatches;
+use crate::error::Result;
+
+pub fn command(args: ArgMatches<'_>) -> Result<()> {
+    let dir = args.ucd_dir()?;
+    let rows: Vec<BidiBracket> = ucd_parse::parse(&dir)?;
+
+    let table: BTreeMap<_, _> = rows
+        .into_iter()
+        .map(|bracket| {
+            (
+                bracket.codepoint.value(),
+                (
+                    bracket.bidi_paired_bracket.value(),
+                    bracket.bidi_paired_bracket_type.as_str(),
+                ),
+            )
+        })
+        .collect();
+
+    let mut wtr = args.writer("bidi_brackets")?;
+    if args.is_present("rust-match") {
+        wtr.codepoint_to_bracket_fn(args.name(), &table)?;
+    } else {
+        wtr.codepoint_to_bracket(args.name(), &table)?;
+    }
+
+    if args.is_present("canonical") {
+        // As per BD16 in UAX #9, brackets are matched after mapping them to
+        // their canonical equivalents. The only brackets affected are those
+        // with a canonical singleton decomposition (e.g., U+2329 maps to
+        // U+3008), so we emit a map for just those.
+        let mut canonical: BTreeMap<u32, u32> = BTreeMap::new();
+        for row in ucd_parse::parse::<_, UnicodeData>(&dir)? {
+            let cp = row.codepoint.value();
+            let decomp = &row.decomposition;
+            if !table.contains_key(&cp)
+                || !decomp.is_canonical()
+                || decomp.mapping().len() != 1
+                || decomp.mapping()[0] == row.codepoint
+            {
+                continue;
+            }
+            let to = decomp.mapping()[0].value();
+            if !table.contains_key(&to) {
+                return err!(
+                    "canonical equivalent U+{:04X} of bracket U+{:04X} \
+                     is not listed in BidiBrackets.txt",
+                    to,
+                    cp
+                );
+            }
+            canonical.insert(cp, to);
+        }
+
+        let name = format!("{}_CANONICAL", args.name());
+        if args.is_present("rust-match") {
+            wtr.codepoint_to_codepoint_fn(&name, &canonical)?;
+        } else {
+            wtr.codepoint_to_codepoint(&name, &canonical)?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use ucd_parse::{self, Block};
+
+use crate::args::ArgMatches;
+use crate::error::Result;
+use crate::util::{print_property_values, PropertyValues};
+
+pub fn command(args: ArgMatches<'_>) -> Result<()> {
+    let dir = args.ucd_dir()?;
+    let propvals = PropertyValues::from_ucd_dir(&dir)?;
+    let filter = args.filter(|name| propvals.canonical("blk", name))?;
+
+    if args.is_present("list-blocks") {
+        return print_property_values(&propvals, "Block");
+    }
+
+    // Block names in Blocks.txt use spaces and hyphens (e.g., "Latin-1
+    // Supplement"), so canonicalize them to the names used in
+    // PropertyValueAliases.txt (e.g., "Latin_1_Supplement").
+    let mut by_name: BTreeMap<String, BTreeSet<u32>> = BTreeMap::new();
+    let blocks: Vec<Block> = ucd_parse::parse(&dir)?;
+    for x in &blocks {
+        let name = propvals.canonical("blk", &x.name)?;
+        if !filter.contains(&name) {
+            continue;
+        }
+        by_name
+            .entry(name)
+            .or_insert(BTreeSet::new())
+            .extend(x.codepoints.into_iter().map(|c| c.value()));
+    }
+
+    let mut wtr = args.writer("block")?;
+    if args.is_present("enum") {
+        wtr.ranges_to_enum(args.name(), &by_name)?;
+    } else if args.is_present("rust-enum") {
+        let variants = by_name.keys().map(String::as_str).collect::<Vec<_>>();
+        wtr.ranges_to_rust_enum(args.name(), &variants, &by_name)?;
+    } else if args.is_present("combined") {
+        wtr.ranges_to_combined(args.name(), &by_name)?;
+    } else {
+        wtr.names(by_name.keys())?;
+        for (name, set) in by_name {
+            wtr.ranges(&name, &set)?;
+        }
+    }
+
+    Ok(())
+}
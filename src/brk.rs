@@ -1,6 +1,8 @@
 use std::collections::{BTreeMap, BTreeSet};
 
-use ucd_parse::{self, GraphemeClusterBreak, SentenceBreak, WordBreak};
+use ucd_parse::{
+    self, GraphemeClusterBreak, LineBreak, SentenceBreak, WordBreak,
+};
 
 use crate::args::ArgMatches;
 use crate::error::Result;
@@ -76,3 +78,49 @@ pub fn sentence(args: ArgMatches<'_>) -> Result<()> {
     }
     Ok(())
 }
+
+pub fn line(args: ArgMatches<'_>) -> Result<()> {
+    let ucd_dir = args.ucd_dir()?;
+    let vals: Vec<LineBreak> = ucd_parse::parse(&ucd_dir)?;
+    let defaults =
+        ucd_parse::parse_missing_defaults::<_, LineBreak>(&ucd_dir)?;
+
+    let mut byval: BTreeMap<String, BTreeSet<u32>> = BTreeMap::new();
+    let mut assigned = BTreeSet::new();
+    for x in &vals {
+        assigned.extend(x.codepoints.into_iter().map(|c| c.value()));
+        byval
+            .entry(x.value.clone())
+            .or_insert(BTreeSet::new())
+            .extend(x.codepoints.into_iter().map(|c| c.value()));
+    }
+
+    // Codepoints that are not listed explicitly get the value of the
+    // @missing line that covers them. When several @missing lines cover the
+    // same codepoint, the last one wins.
+    let mut missing: BTreeMap<u32, String> = BTreeMap::new();
+    for (codepoints, value) in defaults {
+        for cp in codepoints {
+            if !assigned.contains(&cp.value()) {
+                missing.insert(cp.value(), value.clone());
+            }
+        }
+    }
+    for (cp, value) in missing {
+        byval.entry(value).or_insert(BTreeSet::new()).insert(cp);
+    }
+
+    let mut wtr = args.writer("line_break")?;
+    if args.is_present("enum") {
+        wtr.ranges_to_enum(args.name(), &byval)?;
+    } else if args.is_present("rust-enum") {
+        let variants = byval.keys().map(String::as_str).collect::<Vec<_>>();
+        wtr.ranges_to_rust_enum(args.name(), &variants, &byval)?;
+    } else {
+        wtr.names(byval.keys())?;
+        for (val, set) in byval {
+            wtr.ranges(&val, &set)?;
+        }
+    }
+    Ok(())
+}
@@ -10,13 +10,14 @@ pub fn command(args: ArgMatches<'_>) -> Result<()> {
     let case_folding: BTreeMap<Codepoint, Vec<CaseFold>> =
         ucd_parse::parse_many_by_codepoint(dir)?;
 
+    let turkic = args.is_present("turkic");
     let compute_all_pairs =
         args.is_present("all-pairs") || args.is_present("circular");
     let mut wtr = args.writer("case_folding_simple")?;
     let mut table = BTreeMap::new();
     let mut table_all = BTreeMap::new();
     for (&cp, case_folds) in &case_folding {
-        let mapping_cp = match choose_fold(case_folds, false)? {
+        let mapping_cp = match choose_fold(case_folds, false, turkic)? {
             None => continue,
             Some(case_fold) => &case_fold.mapping,
         };
@@ -83,14 +84,58 @@ pub fn command(args: ArgMatches<'_>) -> Result<()> {
     Ok(())
 }
 
+pub fn command_full(args: ArgMatches<'_>) -> Result<()> {
+    let dir = args.ucd_dir()?;
+    let case_folding: BTreeMap<Codepoint, Vec<CaseFold>> =
+        ucd_parse::parse_many_by_codepoint(dir)?;
+    let turkic = args.is_present("turkic");
+
+    let mut table = BTreeMap::new();
+    for (&cp, case_folds) in &case_folding {
+        let mapping = match choose_fold(case_folds, true, turkic)? {
+            None => continue,
+            Some(case_fold) => &case_fold.mapping,
+        };
+        let mapping: Vec<u32> = mapping.iter().map(|cp| cp.value()).collect();
+        table.insert(cp.value(), mapping);
+    }
+
+    let mut wtr = args.writer("case_folding_full")?;
+    let flat = args.is_present("flat-table");
+    wtr.codepoint_to_codepoints(args.name(), &table, flat)?;
+    Ok(())
+}
+
 /// Given a sequence of case fold mappings, choose exactly one mapping based
 /// on the mapping's status. If `full` is true, then full case mappings are
 /// selected, otherwise simple case mappings are selected. If there are
 /// multiple valid choices, then an error is returned.
+///
+/// If `turkic` is true, then a Turkic mapping (status `T`) is selected in
+/// place of the Common, Full or Simple mapping whenever one exists.
 fn choose_fold(
     case_folds: &[CaseFold],
     full: bool,
+    turkic: bool,
 ) -> Result<Option<&CaseFold>> {
+    if turkic {
+        let mut choice = None;
+        for case_fold in case_folds {
+            if case_fold.status == CaseStatus::Special {
+                if choice.is_some() {
+                    return err!(
+                        "found multiple Turkic matches from: {:?}",
+                        case_folds
+                    );
+                }
+                choice = Some(case_fold);
+            }
+        }
+        if choice.is_some() {
+            return Ok(choice);
+        }
+    }
+
     let mut choice = None;
     for case_fold in case_folds {
         if (full && case_fold.status == CaseStatus::Full)
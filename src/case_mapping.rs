@@ -1,9 +1,13 @@
 use std::collections::BTreeMap;
 
-use ucd_parse::{SpecialCaseMapping, UcdFile, UnicodeData};
+use ucd_parse::{
+    Codepoint, SpecialCaseCondition, SpecialCaseContext, SpecialCaseMapping,
+    UcdFile, UnicodeData,
+};
 
 use crate::args::ArgMatches;
 use crate::error::Result;
+use crate::writer::{CasingCondition, ConditionalMapping};
 
 pub fn command(args: ArgMatches<'_>) -> Result<()> {
     let dir = args.ucd_dir()?;
@@ -34,11 +38,11 @@ pub fn command(args: ArgMatches<'_>) -> Result<()> {
         wtr.codepoint_to_codepoint("UPPER", &lower_map)?;
         wtr.codepoint_to_codepoint("TITLE", &title_map)?;
     } else {
+        let mut conditional = vec![];
         for special in SpecialCaseMapping::from_dir(&dir)? {
             let special = special?;
             if !special.conditions.is_empty() {
-                // There should probably be an option to output these too, but
-                // I'm not sure how they're typically used...
+                conditional.push(special);
                 continue;
             }
             if !special.lowercase.is_empty() {
@@ -64,6 +68,91 @@ pub fn command(args: ArgMatches<'_>) -> Result<()> {
         wtr.codepoint_to_codepoints("LOWER", &lower_map, flat)?;
         wtr.codepoint_to_codepoints("UPPER", &upper_map, flat)?;
         wtr.codepoint_to_codepoints("TITLE", &title_map, flat)?;
+
+        if args.is_present("conditional") {
+            let lower =
+                conditional_table(&conditional, &lower_map, |x| &x.lowercase)?;
+            let upper =
+                conditional_table(&conditional, &upper_map, |x| &x.uppercase)?;
+            let title =
+                conditional_table(&conditional, &title_map, |x| &x.titlecase)?;
+            let contexts = [
+                SpecialCaseContext::FinalSigma,
+                SpecialCaseContext::AfterSoftDotted,
+                SpecialCaseContext::MoreAbove,
+                SpecialCaseContext::BeforeDot,
+                SpecialCaseContext::AfterI,
+            ];
+            let contexts: Vec<&str> =
+                contexts.iter().map(|c| c.as_str()).collect();
+            wtr.casing_condition_types(&contexts)?;
+            wtr.codepoint_to_conditional_codepoints(
+                "LOWER_CONDITIONAL",
+                &lower,
+            )?;
+            wtr.codepoint_to_conditional_codepoints(
+                "UPPER_CONDITIONAL",
+                &upper,
+            )?;
+            wtr.codepoint_to_conditional_codepoints(
+                "TITLE_CONDITIONAL",
+                &title,
+            )?;
+        }
     }
     Ok(())
 }
+
+/// Build a table of conditional mappings in one direction, where `mapping`
+/// selects the direction from each conditional special case mapping and
+/// `unconditional` is the unconditional mapping table for that direction.
+///
+/// Conditional mappings that are equivalent to the unconditional mapping
+/// (which is the identity mapping for codepoints not in `unconditional`) are
+/// omitted, since SpecialCasing.txt lists every direction on each line even
+/// when only one of them is affected by the conditions.
+fn conditional_table<F>(
+    conditional: &[SpecialCaseMapping],
+    unconditional: &BTreeMap<u32, Vec<u32>>,
+    mapping: F,
+) -> Result<Vec<ConditionalMapping>>
+where
+    F: Fn(&SpecialCaseMapping) -> &Vec<Codepoint>,
+{
+    let mut table = vec![];
+    for special in conditional {
+        let cp = special.codepoint.value();
+        let mapping: Vec<u32> =
+            mapping(special).iter().map(|v| v.value()).collect();
+        let default = match unconditional.get(&cp) {
+            Some(default) => default.clone(),
+            None => vec![cp],
+        };
+        if mapping == default {
+            continue;
+        }
+        let conditions = special
+            .parse_conditions()?
+            .into_iter()
+            .map(|c| match c {
+                SpecialCaseCondition::Language(lang) => {
+                    CasingCondition::Language(lang)
+                }
+                SpecialCaseCondition::Context(context) => {
+                    CasingCondition::Context(context.as_str().to_string())
+                }
+                SpecialCaseCondition::NotContext(context) => {
+                    CasingCondition::NotContext(context.as_str().to_string())
+                }
+                SpecialCaseCondition::Other(other) => {
+                    CasingCondition::Other(other)
+                }
+            })
+            .collect();
+        table.push((cp, conditions, mapping));
+    }
+    // SpecialCasing.txt is not sorted by codepoint. Sorting is stable, so
+    // multiple entries for the same codepoint retain their relative order.
+    table.sort_by_key(|&(cp, _, _)| cp);
+    Ok(table)
+}
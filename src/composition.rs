@@ -0,0 +1,54 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use ucd_parse::{self, CompositionExclusion, UnicodeData};
+
+use crate::args::ArgMatches;
+use crate::error::Result;
+
+pub fn command(args: ArgMatches<'_>) -> Result<()> {
+    let dir = args.ucd_dir()?;
+    let rows: Vec<UnicodeData> = ucd_parse::parse(&dir)?;
+    let exclusions: BTreeSet<u32> =
+        ucd_parse::parse::<_, CompositionExclusion>(&dir)?
+            .into_iter()
+            .map(|x| x.codepoint.value())
+            .collect();
+
+    let ccc: BTreeMap<u32, u8> = rows
+        .iter()
+        .map(|row| (row.codepoint.value(), row.canonical_combining_class))
+        .collect();
+    let is_starter = |cp: u32| ccc.get(&cp).cloned().unwrap_or(0) == 0;
+
+    // A primary composite is a character with a canonical decomposition
+    // mapping that is not excluded from composition. As per UAX #15, a
+    // character is excluded if it is a singleton (its decomposition is a
+    // single codepoint), if it is a non-starter decomposition (either it or
+    // the first codepoint of its decomposition is not a starter) or if it is
+    // listed in CompositionExclusions.txt.
+    let mut table: BTreeMap<(u32, u32), u32> = BTreeMap::new();
+    for row in &rows {
+        let cp = row.codepoint.value();
+        let decomp = &row.decomposition;
+        if !decomp.is_canonical() || decomp.mapping().len() != 2 {
+            continue;
+        }
+        let (first, second) =
+            (decomp.mapping()[0].value(), decomp.mapping()[1].value());
+        if !is_starter(cp) || !is_starter(first) {
+            continue;
+        }
+        if exclusions.contains(&cp) {
+            continue;
+        }
+        table.insert((first, second), cp);
+    }
+
+    let mut wtr = args.writer("composition")?;
+    if args.is_present("rust-match") {
+        wtr.codepoint_pair_to_codepoint_fn(args.name(), &table)?;
+    } else {
+        wtr.codepoint_pair_to_codepoint(args.name(), &table)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use std::collections::BTreeMap;
+
+use ucd_parse::{self, UnicodeData};
+
+use crate::args::ArgMatches;
+use crate::error::Result;
+
+/// The first trailing consonant jamo, as defined in Unicode 3.12. A trailing
+/// consonant index of 0 means the syllable has no trailing consonant.
+const HANGUL_T_BASE: u32 = 0x11A7;
+
+pub fn command(args: ArgMatches<'_>) -> Result<()> {
+    let dir = args.ucd_dir()?;
+    let rows: Vec<UnicodeData> = ucd_parse::parse(&dir)?;
+    let compat = args.is_present("compat");
+
+    let mut decomps: BTreeMap<u32, Vec<u32>> = BTreeMap::new();
+    for row in &rows {
+        let decomp = &row.decomposition;
+        // A canonical mapping to the codepoint itself means that this row
+        // has no decomposition mapping at all.
+        if decomp.is_canonical() && decomp.mapping() == [row.codepoint] {
+            continue;
+        }
+        if !compat && !decomp.is_canonical() {
+            continue;
+        }
+        decomps.insert(
+            row.codepoint.value(),
+            decomp.mapping().iter().map(|cp| cp.value()).collect(),
+        );
+    }
+
+    // Hangul syllables are not listed individually in UnicodeData.txt, since
+    // their decompositions are algorithmic. The mapping of a syllable is
+    // either the pair <L, V> or the pair <LV, T>. (The latter is expanded to
+    // <L, V, T> when computing full decompositions.)
+    if !args.is_present("no-hangul") {
+        for &(start, end) in ucd_util::RANGE_HANGUL_SYLLABLE {
+            for cp in start..=end {
+                let (l, v, t) =
+                    ucd_util::hangul_full_canonical_decomposition(cp).unwrap();
+                let mapping = match t {
+                    None => vec![l, v],
+                    Some(t) => vec![cp - (t - HANGUL_T_BASE), t],
+                };
+                decomps.insert(cp, mapping);
+            }
+        }
+    }
+
+    // If requested, compute the full decomposition of every codepoint by
+    // recursively applying the mappings collected above.
+    if args.is_present("recursive") {
+        let mut expanded = BTreeMap::new();
+        for &cp in decomps.keys() {
+            let mut mapping = vec![];
+            decompose(&decomps, cp, &mut mapping);
+            expanded.insert(cp, mapping);
+        }
+        decomps = expanded;
+    }
+
+    let mut wtr = args.writer("decomposition")?;
+    let flat = args.is_present("flat-table");
+    wtr.codepoint_to_codepoints(args.name(), &decomps, flat)?;
+    Ok(())
+}
+
+/// Write the full decomposition of the given codepoint to `mapping` by
+/// recursively applying the given decomposition mappings.
+fn decompose(
+    decomps: &BTreeMap<u32, Vec<u32>>,
+    cp: u32,
+    mapping: &mut Vec<u32>,
+) {
+    match decomps.get(&cp) {
+        None => mapping.push(cp),
+        Some(cps) => {
+            for &cp in cps {
+                decompose(decomps, cp, mapping);
+            }
+        }
+    }
+}
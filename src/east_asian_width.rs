@@ -0,0 +1,78 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use ucd_parse::{self, EastAsianWidth};
+
+use crate::args::ArgMatches;
+use crate::error::Result;
+use crate::util::{print_property_values, PropertyValues};
+
+pub fn command(args: ArgMatches<'_>) -> Result<()> {
+    let dir = args.ucd_dir()?;
+    let propvals = PropertyValues::from_ucd_dir(&dir)?;
+    let rows: Vec<EastAsianWidth> = ucd_parse::parse(&dir)?;
+    let filter = args.filter(|name| propvals.canonical("ea", name))?;
+    let use_short_names = args.is_present("short-names");
+
+    // If we were tasked with listing the available values, then do that and
+    // quit.
+    if args.is_present("list-values") {
+        return print_property_values(&propvals, "East_Asian_Width");
+    }
+
+    // Collect each width into an ordered set. Widths are keyed by the
+    // abbreviation found in EastAsianWidth.txt so that the short names can be
+    // emitted if requested.
+    let mut by_width: BTreeMap<String, BTreeSet<u32>> = BTreeMap::new();
+    let mut assigned = BTreeSet::new();
+    for row in rows {
+        assigned.extend(row.codepoints.into_iter().map(|c| c.value()));
+        by_width
+            .entry(row.width)
+            .or_insert(BTreeSet::new())
+            .extend(row.codepoints.into_iter().map(|c| c.value()));
+    }
+
+    // Process the codepoints that are not listed as per the note in
+    // EastAsianWidth.txt:
+    //
+    // - All code points, assigned or unassigned, that are not listed
+    //   explicitly are given the value "N".
+    let neutral = by_width.entry("N".to_string()).or_insert(BTreeSet::new());
+    for cp in 0..=0x10FFFF {
+        if !assigned.contains(&cp) {
+            neutral.insert(cp);
+        }
+    }
+
+    // Finally, rename and filter the sets according to what the user asked
+    // for.
+    let mut by_name: BTreeMap<String, BTreeSet<u32>> = BTreeMap::new();
+    for (short_name, set) in by_width {
+        let name = propvals.canonical("ea", &short_name)?;
+        if !filter.contains(&name) {
+            continue;
+        }
+        if use_short_names {
+            by_name.insert(short_name, set);
+        } else {
+            by_name.insert(name, set);
+        }
+    }
+
+    let mut wtr = args.writer("east_asian_width")?;
+    if args.is_present("enum") {
+        wtr.ranges_to_enum(args.name(), &by_name)?;
+    } else if args.is_present("rust-enum") {
+        let variants = by_name.keys().map(String::as_str).collect::<Vec<_>>();
+        wtr.ranges_to_rust_enum(args.name(), &variants, &by_name)?;
+    } else if args.is_present("combined") {
+        wtr.ranges_to_combined(args.name(), &by_name)?;
+    } else {
+        wtr.names(by_name.keys())?;
+        for (name, set) in by_name {
+            wtr.ranges(&name, &set)?;
+        }
+    }
+
+    Ok(())
+}
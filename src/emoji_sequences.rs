@@ -0,0 +1,51 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use ucd_parse::{self, EmojiSequence, EmojiZwjSequence};
+
+use crate::args::ArgMatches;
+use crate::error::Result;
+
+pub fn command(args: ArgMatches<'_>) -> Result<()> {
+    let dir = args.ucd_dir()?;
+    let sequences: Vec<EmojiSequence> = ucd_parse::parse(&dir)?;
+    let zwj_sequences: Vec<EmojiZwjSequence> = ucd_parse::parse(&dir)?;
+
+    let mut by_name: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    let rows = sequences
+        .into_iter()
+        .map(|x| (x.property, x.codepoints))
+        .chain(zwj_sequences.into_iter().map(|x| (x.property, x.codepoints)));
+    for (property, codepoints) in rows {
+        by_name
+            .entry(property)
+            .or_insert(BTreeSet::new())
+            .extend(codepoints.strings());
+    }
+    // As per UTS #51, RGI_Emoji is the union of all of the other properties
+    // of strings defined in the emoji sequence files.
+    let rgi_emoji: BTreeSet<String> =
+        by_name.values().flatten().cloned().collect();
+    by_name.insert("RGI_Emoji".to_string(), rgi_emoji);
+
+    let filter = args.filter(|name| {
+        match by_name.keys().find(|k| k.eq_ignore_ascii_case(name)) {
+            Some(k) => Ok(k.clone()),
+            None => err!("unrecognized emoji sequence property: {}", name),
+        }
+    })?;
+    if args.is_present("list-properties") {
+        for name in by_name.keys() {
+            println!("{}", name);
+        }
+        return Ok(());
+    }
+
+    let mut wtr = args.writer("emoji_sequences")?;
+    wtr.string_set_names(by_name.keys().filter(|n| filter.contains(n)))?;
+    for (name, set) in by_name {
+        if filter.contains(&name) {
+            wtr.strings(&name, &set)?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use ucd_parse::{
+    self, parse_missing_defaults, IndicPositionalCategory,
+    IndicSyllabicCategory,
+};
+
+use crate::args::ArgMatches;
+use crate::error::Result;
+use crate::util::PropertyValues;
+
+pub fn command(args: ArgMatches<'_>) -> Result<()> {
+    let dir = args.ucd_dir()?;
+    let propvals = PropertyValues::from_ucd_dir(&dir)?;
+
+    // Both files list only the codepoints that have a value other than the
+    // default, which is given by the @missing line in each file.
+    let (property, defaults, rows, name) = if args.is_present("positional") {
+        let rows: Vec<IndicPositionalCategory> = ucd_parse::parse(&dir)?;
+        let rows = rows
+            .into_iter()
+            .map(|x| (x.codepoints, x.category))
+            .collect::<Vec<_>>();
+        let defaults =
+            parse_missing_defaults::<_, IndicPositionalCategory>(&dir)?;
+        ("InPC", defaults, rows, "indic_positional_category")
+    } else {
+        let rows: Vec<IndicSyllabicCategory> = ucd_parse::parse(&dir)?;
+        let rows = rows
+            .into_iter()
+            .map(|x| (x.codepoints, x.category))
+            .collect::<Vec<_>>();
+        let defaults =
+            parse_missing_defaults::<_, IndicSyllabicCategory>(&dir)?;
+        ("InSC", defaults, rows, "indic_syllabic_category")
+    };
+
+    let mut by_value: BTreeMap<String, BTreeSet<u32>> = BTreeMap::new();
+    let mut assigned = BTreeSet::new();
+    for (codepoints, value) in rows {
+        let value = propvals.canonical(property, &value)?;
+        assigned.extend(codepoints.into_iter().map(|c| c.value()));
+        by_value
+            .entry(value)
+            .or_insert(BTreeSet::new())
+            .extend(codepoints.into_iter().map(|c| c.value()));
+    }
+    // When several @missing lines cover the same codepoint, the last one
+    // wins.
+    let mut missing: BTreeMap<u32, String> = BTreeMap::new();
+    for (codepoints, value) in defaults {
+        let value = propvals.canonical(property, &value)?;
+        for cp in codepoints {
+            if !assigned.contains(&cp.value()) {
+                missing.insert(cp.value(), value.clone());
+            }
+        }
+    }
+    for (cp, value) in missing {
+        by_value.entry(value).or_insert(BTreeSet::new()).insert(cp);
+    }
+
+    // The default table name depends on which property was requested, so
+    // only use the --name flag if it was given explicitly.
+    let table_name = if args.occurrences_of("name") > 0 {
+        args.name().to_string()
+    } else {
+        name.to_uppercase()
+    };
+
+    let mut wtr = args.writer(name)?;
+    if args.is_present("enum") {
+        wtr.ranges_to_enum(&table_name, &by_value)?;
+    } else if args.is_present("rust-enum") {
+        let variants = by_value.keys().map(String::as_str).collect::<Vec<_>>();
+        wtr.ranges_to_rust_enum(&table_name, &variants, &by_value)?;
+    } else {
+        wtr.names(by_value.keys())?;
+        for (value, set) in by_value {
+            wtr.ranges(&value, &set)?;
+        }
+    }
+
+    Ok(())
+}
@@ -14,6 +14,6 @@ pub fn command(args: ArgMatches<'_>) -> Result<()> {
     for (cp, jamo) in jamo_map {
         map.insert(cp.value(), jamo.name);
     }
-    wtr.codepoint_to_string(args.name(), &map)?;
+    wtr.codepoint_to_string(args.name(), &map, false)?;
     Ok(())
 }
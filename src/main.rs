@@ -19,20 +19,35 @@ mod util;
 mod writer;
 
 mod age;
+mod bidi_brackets;
 mod bidi_class;
 mod bidi_mirroring_glyph;
+mod block;
 mod brk;
 mod canonical_combining_class;
 mod case_folding;
 mod case_mapping;
+mod composition;
+mod decomposition;
+mod east_asian_width;
+mod emoji_sequences;
 mod general_category;
+mod hangul_syllable_type;
+mod indic;
 mod jamo_short_name;
 mod joining_group;
 mod joining_type;
 mod names;
+mod names_list;
+mod normalization;
+mod numeric;
 mod property_bool;
+mod radical_stroke;
 mod regex;
 mod script;
+mod unihan;
+mod variation_sequences;
+mod vertical_orientation;
 
 fn main() {
     if let Err(err) = run() {
@@ -51,9 +66,15 @@ fn run() -> Result<()> {
         ("bidi-mirroring-glyph", Some(m)) => {
             bidi_mirroring_glyph::command(ArgMatches::new(m))
         }
+        ("bidi-brackets", Some(m)) => {
+            bidi_brackets::command(ArgMatches::new(m))
+        }
         ("canonical-combining-class", Some(m)) => {
             canonical_combining_class::command(ArgMatches::new(m))
         }
+        ("east-asian-width", Some(m)) => {
+            east_asian_width::command(ArgMatches::new(m))
+        }
         ("general-category", Some(m)) => {
             general_category::command(ArgMatches::new(m))
         }
@@ -61,9 +82,20 @@ fn run() -> Result<()> {
         ("script-extension", Some(m)) => {
             script::command_script_extension(ArgMatches::new(m))
         }
+        ("block", Some(m)) => block::command(ArgMatches::new(m)),
         ("property-bool", Some(m)) => {
             property_bool::command(ArgMatches::new(m))
         }
+        ("emoji-sequences", Some(m)) => {
+            emoji_sequences::command(ArgMatches::new(m))
+        }
+        ("variation-sequences", Some(m)) => {
+            variation_sequences::command(ArgMatches::new(m))
+        }
+        ("unihan", Some(m)) => unihan::command(ArgMatches::new(m)),
+        ("radical-stroke", Some(m)) => {
+            radical_stroke::command(ArgMatches::new(m))
+        }
         ("age", Some(m)) => age::command(ArgMatches::new(m)),
         ("perl-word", Some(m)) => {
             property_bool::command_perl_word(ArgMatches::new(m))
@@ -76,6 +108,15 @@ fn run() -> Result<()> {
         }
         ("joining-type", Some(m)) => joining_type::command(ArgMatches::new(m)),
         ("names", Some(m)) => names::command(ArgMatches::new(m)),
+        ("names-list", Some(m)) => names_list::command(ArgMatches::new(m)),
+        ("numeric", Some(m)) => numeric::command(ArgMatches::new(m)),
+        ("indic", Some(m)) => indic::command(ArgMatches::new(m)),
+        ("hangul-syllable-type", Some(m)) => {
+            hangul_syllable_type::command(ArgMatches::new(m))
+        }
+        ("vertical-orientation", Some(m)) => {
+            vertical_orientation::command(ArgMatches::new(m))
+        }
         ("property-names", Some(m)) => cmd_property_names(ArgMatches::new(m)),
         ("property-values", Some(m)) => {
             cmd_property_values(ArgMatches::new(m))
@@ -83,12 +124,26 @@ fn run() -> Result<()> {
         ("case-folding-simple", Some(m)) => {
             case_folding::command(ArgMatches::new(m))
         }
+        ("case-folding-full", Some(m)) => {
+            case_folding::command_full(ArgMatches::new(m))
+        }
         ("case-mapping", Some(m)) => case_mapping::command(ArgMatches::new(m)),
+        ("composition", Some(m)) => composition::command(ArgMatches::new(m)),
+        ("decomposition", Some(m)) => {
+            decomposition::command(ArgMatches::new(m))
+        }
+        ("normalization-quick-check", Some(m)) => {
+            normalization::command_quick_check(ArgMatches::new(m))
+        }
+        ("nfkc-casefold", Some(m)) => {
+            normalization::command_nfkc_casefold(ArgMatches::new(m))
+        }
         ("grapheme-cluster-break", Some(m)) => {
             brk::grapheme_cluster(ArgMatches::new(m))
         }
         ("word-break", Some(m)) => brk::word(ArgMatches::new(m)),
         ("sentence-break", Some(m)) => brk::sentence(ArgMatches::new(m)),
+        ("line-break", Some(m)) => brk::line(ArgMatches::new(m)),
         ("dfa", Some(m)) => regex::command_dfa(ArgMatches::new(m)),
         ("regex", Some(m)) => regex::command_regex(ArgMatches::new(m)),
         ("test-unicode-data", Some(m)) => {
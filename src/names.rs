@@ -1,6 +1,10 @@
 use std::collections::BTreeMap;
+use std::path::Path;
 
-use ucd_parse::{self, Codepoint, NameAlias, UnicodeData};
+use ucd_parse::{
+    self, Codepoint, NameAlias, NameAliasLabel, NamedSequence,
+    ProvisionalNamedSequence, UnicodeData,
+};
 use ucd_util;
 
 use crate::args::ArgMatches;
@@ -12,13 +16,28 @@ pub fn command(args: ArgMatches<'_>) -> Result<()> {
     let aliases = if args.is_present("no-aliases") {
         None
     } else {
-        Some(ucd_parse::parse_many_by_codepoint(&dir)?)
+        let mut aliases: BTreeMap<Codepoint, Vec<NameAlias>> =
+            ucd_parse::parse_many_by_codepoint(&dir)?;
+        if let Some(labels) = args.value_of("alias-labels") {
+            let labels = parse_alias_labels(labels)?;
+            for name_aliases in aliases.values_mut() {
+                name_aliases.retain(|a| labels.contains(&a.label));
+            }
+            aliases.retain(|_, name_aliases| !name_aliases.is_empty());
+        }
+        Some(aliases)
     };
+    if args.is_present("reverse") {
+        return command_reverse(&args, &data, &aliases);
+    }
+    // Algorithmically generated names are never stored in a compressed
+    // table, since they would each add a distinct word to its dictionary.
+    let compressed = args.is_present("compressed");
     let mut names = names_to_codepoint(
         &data,
         &aliases,
-        !args.is_present("no-ideograph"),
-        !args.is_present("no-hangul"),
+        !compressed && !args.is_present("no-ideograph"),
+        !compressed && !args.is_present("no-hangul"),
     );
     if args.is_present("normalize") {
         names = names
@@ -31,7 +50,19 @@ pub fn command(args: ArgMatches<'_>) -> Result<()> {
     }
 
     let mut wtr = args.writer("names")?;
-    if args.is_present("tagged") {
+    if compressed {
+        // List the name from UnicodeData.txt first, so that it is the one
+        // found when looking up the name of a codepoint.
+        let mut entries: Vec<(u32, bool, String)> = names
+            .into_iter()
+            .map(|(name, (tag, cp))| (cp, tag != NameTag::Explicit, name))
+            .collect();
+        entries.sort();
+        let entries: Vec<(u32, String)> =
+            entries.into_iter().map(|(cp, _, name)| (cp, name)).collect();
+        let ranges = algorithmic_ranges(&args, &data);
+        wtr.names_compressed(args.name(), &entries, &ranges)?;
+    } else if args.is_present("tagged") {
         let mut map = BTreeMap::new();
         for (name, (tag, cp)) in names {
             map.insert(name, tag.with_codepoint(cp));
@@ -44,19 +75,184 @@ pub fn command(args: ArgMatches<'_>) -> Result<()> {
         }
         wtr.string_to_codepoint(args.name(), &map)?;
     }
+
+    if args.is_present("named-sequences") {
+        let mut sequences = named_sequences_to_codepoints(
+            &dir,
+            args.is_present("provisional"),
+        )?;
+        if args.is_present("normalize") {
+            sequences = sequences
+                .into_iter()
+                .map(|(mut name, cps)| {
+                    ucd_util::character_name_normalize(&mut name);
+                    (name, cps)
+                })
+                .collect();
+        }
+        let name = format!("{}_SEQUENCES", args.name());
+        wtr.string_to_codepoints(&name, &sequences)?;
+    }
+    Ok(())
+}
+
+/// Build a map from the name of every named character sequence to its
+/// corresponding sequence of codepoints. Provisional named sequences are
+/// only included when `provisional` is true.
+fn named_sequences_to_codepoints<P: AsRef<Path>>(
+    dir: P,
+    provisional: bool,
+) -> Result<BTreeMap<String, Vec<u32>>> {
+    let mut map = BTreeMap::new();
+    for seq in ucd_parse::parse::<_, NamedSequence>(&dir)? {
+        let cps = seq.codepoints.iter().map(|cp| cp.value()).collect();
+        map.insert(seq.name, cps);
+    }
+    if provisional {
+        for seq in ucd_parse::parse::<_, ProvisionalNamedSequence>(&dir)? {
+            let cps = seq.codepoints.iter().map(|cp| cp.value()).collect();
+            map.insert(seq.name, cps);
+        }
+    }
+    Ok(map)
+}
+
+/// Emit a map from codepoint to its preferred name.
+///
+/// Algorithmically generated names are not included in the map. Instead, the
+/// ranges of codepoints with such names are emitted as a separate table, where
+/// each range is associated with the kind of name its codepoints have.
+fn command_reverse(
+    args: &ArgMatches<'_>,
+    data: &BTreeMap<Codepoint, UnicodeData>,
+    aliases: &Option<BTreeMap<Codepoint, Vec<NameAlias>>>,
+) -> Result<()> {
+    let preferred = match args.value_of("prefer-alias") {
+        None => vec![],
+        Some(labels) => parse_alias_labels(labels)?,
+    };
+
+    let mut map = codepoint_to_name(data, aliases, &preferred);
+    if args.is_present("normalize") {
+        for name in map.values_mut() {
+            ucd_util::character_name_normalize(name);
+        }
+    }
+
+    let mut ranges = algorithmic_ranges(args, data);
+    if args.is_present("normalize") {
+        for &mut (_, _, ref mut prefix) in &mut ranges {
+            ucd_util::character_name_normalize(prefix);
+        }
+    }
+
+    let mut wtr = args.writer("names")?;
+    wtr.codepoint_to_string(args.name(), &map, true)?;
+    if !ranges.is_empty() {
+        let name = format!("{}_ALGORITHMIC", args.name());
+        wtr.ranges_to_string(&name, &ranges)?;
+    }
     Ok(())
 }
 
+/// Parse a comma separated list of name alias labels, in the order given.
+fn parse_alias_labels(labels: &str) -> Result<Vec<NameAliasLabel>> {
+    let mut parsed = vec![];
+    for label in labels.split(',') {
+        parsed.push(label.trim().parse()?);
+    }
+    Ok(parsed)
+}
+
+/// Return the ranges of codepoints with algorithmically generated names, where
+/// each range is associated with the prefix of the names of its codepoints.
+///
+/// The ranges are taken from the `<..., First>` and `<..., Last>` records in
+/// UnicodeData.txt. The name of an ideograph is its prefix followed by its
+/// codepoint in hexadecimal, and the name of a Hangul syllable is its prefix
+/// followed by the short names of its jamo. Ranges of codepoints without a
+/// name, such as surrogates and private use characters, are skipped.
+fn algorithmic_ranges(
+    args: &ArgMatches<'_>,
+    data: &BTreeMap<Codepoint, UnicodeData>,
+) -> Vec<(u32, u32, String)> {
+    let mut ranges = vec![];
+    let mut start = None;
+    for (cp, datum) in data {
+        if datum.is_range_start() {
+            start = Some(cp.value());
+            continue;
+        }
+        let start = match start.take() {
+            Some(start) if datum.is_range_end() => start,
+            _ => continue,
+        };
+        let label = datum.name[1..].split(',').next().unwrap();
+        let prefix = if label == "Hangul Syllable" {
+            if args.is_present("no-hangul") {
+                continue;
+            }
+            "HANGUL SYLLABLE "
+        } else if label.starts_with("CJK Ideograph") {
+            if args.is_present("no-ideograph") {
+                continue;
+            }
+            "CJK UNIFIED IDEOGRAPH-"
+        } else if label.starts_with("Tangut Ideograph") {
+            if args.is_present("no-ideograph") {
+                continue;
+            }
+            "TANGUT IDEOGRAPH-"
+        } else {
+            continue;
+        };
+        ranges.push((start, cp.value(), prefix.to_string()));
+    }
+    ranges
+}
+
+/// Build a map from every codepoint with an explicit name to its preferred
+/// name.
+///
+/// The preferred name of a codepoint is its first alias whose label appears
+/// earliest in `preferred`. If it has no such alias, then its name from
+/// UnicodeData.txt is used. Codepoints without either are omitted.
+fn codepoint_to_name(
+    data: &BTreeMap<Codepoint, UnicodeData>,
+    aliases: &Option<BTreeMap<Codepoint, Vec<NameAlias>>>,
+    preferred: &[NameAliasLabel],
+) -> BTreeMap<u32, String> {
+    let mut map = BTreeMap::new();
+    for (cp, datum) in data {
+        let isnull = datum.name.is_empty()
+            || (datum.name.starts_with('<') && datum.name.ends_with('>'));
+        if !isnull {
+            map.insert(cp.value(), datum.name.clone());
+        }
+    }
+    if let Some(ref alias_map) = *aliases {
+        for (cp, aliases) in alias_map {
+            let best = preferred
+                .iter()
+                .find_map(|&label| aliases.iter().find(|a| a.label == label));
+            if let Some(name_alias) = best {
+                map.insert(cp.value(), name_alias.alias.clone());
+            }
+        }
+    }
+    map
+}
+
 /// A tag indicating how the name of a codepoint was found.
 ///
 /// When a name has both an algorithmically generated name and an
 /// explicit/alias name, then the algorithmically generated tag is preferred.
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 enum NameTag {
     /// The name is listed explicitly in UnicodeData.txt.
     Explicit,
-    /// The name was taken from NameAliases.txt.
-    Alias,
+    /// The name was taken from NameAliases.txt, with the given labels.
+    Alias(Vec<NameAliasLabel>),
     /// The name is an algorithmically generated Hangul syllable.
     Hangul,
     /// The name is an algorithmically generated ideograph.
@@ -68,13 +264,32 @@ impl NameTag {
         use self::NameTag::*;
         match *self {
             Explicit => (1 << 33) | (cp as u64),
-            Alias => (1 << 34) | (cp as u64),
+            Alias(ref labels) => {
+                let mut tagged = (1 << 34) | (cp as u64);
+                for &label in labels {
+                    tagged |= 1 << label_bit(label);
+                }
+                tagged
+            }
             Hangul => (1 << 35) | (cp as u64),
             Ideograph => (1 << 36) | (cp as u64),
         }
     }
 }
 
+/// Return the bit that is set in a tagged codepoint when the name is an alias
+/// with the given label.
+fn label_bit(label: NameAliasLabel) -> u64 {
+    use ucd_parse::NameAliasLabel::*;
+    match label {
+        Correction => 37,
+        Control => 38,
+        Alternate => 39,
+        Figment => 40,
+        Abbreviation => 41,
+    }
+}
+
 /// Build one big map in memory from every possible name of a character to its
 /// corresponding codepoint. One codepoint may be pointed to by multiple names.
 ///
@@ -100,7 +315,18 @@ fn names_to_codepoint(
     if let Some(ref alias_map) = *aliases {
         for (cp, aliases) in alias_map {
             for name_alias in aliases {
-                let v = (NameTag::Alias, cp.value());
+                // The same alias may be given with more than one label, in
+                // which case its labels are merged.
+                let mut labels = match map.get(&name_alias.alias) {
+                    Some(&(NameTag::Alias(ref labels), cp2))
+                        if cp2 == cp.value() =>
+                    {
+                        labels.clone()
+                    }
+                    _ => vec![],
+                };
+                labels.push(name_alias.label);
+                let v = (NameTag::Alias(labels), cp.value());
                 map.insert(name_alias.alias.clone(), v);
             }
         }
@@ -131,3 +357,77 @@ fn names_to_codepoint(
     }
     map
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use ucd_parse::{Codepoint, NameAlias, NameAliasLabel, UnicodeData};
+
+    use super::{names_to_codepoint, NameTag};
+
+    fn datum(cp: u32, name: &str) -> (Codepoint, UnicodeData) {
+        let codepoint = Codepoint::from_u32(cp).unwrap();
+        let datum = UnicodeData {
+            codepoint,
+            name: name.to_string(),
+            ..UnicodeData::default()
+        };
+        (codepoint, datum)
+    }
+
+    fn alias(cp: u32, alias: &str, label: NameAliasLabel) -> NameAlias {
+        NameAlias {
+            codepoint: Codepoint::from_u32(cp).unwrap(),
+            alias: alias.to_string(),
+            label,
+        }
+    }
+
+    #[test]
+    fn alias_with_two_labels() {
+        let data = vec![datum(0x0A, "")].into_iter().collect();
+        let mut aliases = BTreeMap::new();
+        aliases.insert(
+            Codepoint::from_u32(0x0A).unwrap(),
+            vec![
+                alias(0x0A, "LINE FEED", NameAliasLabel::Control),
+                alias(0x0A, "LF", NameAliasLabel::Abbreviation),
+                alias(0x0A, "LINE FEED", NameAliasLabel::Alternate),
+            ],
+        );
+        let map = names_to_codepoint(&data, &Some(aliases), false, false);
+        let labels = vec![NameAliasLabel::Control, NameAliasLabel::Alternate];
+        assert_eq!(map["LINE FEED"], (NameTag::Alias(labels), 0x0A));
+        let labels = vec![NameAliasLabel::Abbreviation];
+        assert_eq!(map["LF"], (NameTag::Alias(labels), 0x0A));
+    }
+
+    #[test]
+    fn alias_collides_with_name() {
+        let data =
+            vec![datum(0x41, "LATIN CAPITAL LETTER A"), datum(0x42, "")]
+                .into_iter()
+                .collect();
+        let mut aliases = BTreeMap::new();
+        aliases.insert(
+            Codepoint::from_u32(0x41).unwrap(),
+            vec![alias(
+                0x41,
+                "LATIN CAPITAL LETTER A",
+                NameAliasLabel::Figment,
+            )],
+        );
+        aliases.insert(
+            Codepoint::from_u32(0x42).unwrap(),
+            vec![alias(
+                0x42,
+                "LATIN CAPITAL LETTER A",
+                NameAliasLabel::Correction,
+            )],
+        );
+        let map = names_to_codepoint(&data, &Some(aliases), false, false);
+        assert_eq!(map["LATIN CAPITAL LETTER A"], (NameTag::Explicit, 0x41));
+        assert_eq!(map.len(), 1);
+    }
+}
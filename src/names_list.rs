@@ -0,0 +1,40 @@
+use std::collections::BTreeMap;
+
+use ucd_parse::{self, NamesListAnnotation};
+
+use crate::args::ArgMatches;
+use crate::error::Result;
+
+pub fn command(args: ArgMatches<'_>) -> Result<()> {
+    let dir = args.ucd_dir()?;
+    let blocks = ucd_parse::parse_names_list(&dir)?;
+
+    let mut aliases: BTreeMap<u32, Vec<String>> = BTreeMap::new();
+    let mut cross_refs: BTreeMap<u32, Vec<u32>> = BTreeMap::new();
+    for entry in blocks.iter().flat_map(|block| block.entries()) {
+        let cp = entry.codepoint.value();
+        for annotation in &entry.annotations {
+            match *annotation {
+                NamesListAnnotation::Alias(ref alias) => {
+                    aliases.entry(cp).or_insert(vec![]).push(alias.clone());
+                }
+                NamesListAnnotation::CrossReference { codepoint, .. } => {
+                    cross_refs
+                        .entry(cp)
+                        .or_insert(vec![])
+                        .push(codepoint.value());
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let mut wtr = args.writer("names_list")?;
+    wtr.codepoint_to_strings(&format!("{}_ALIASES", args.name()), &aliases)?;
+    wtr.codepoint_to_codepoints(
+        &format!("{}_CROSS_REFERENCES", args.name()),
+        &cross_refs,
+        false,
+    )?;
+    Ok(())
+}
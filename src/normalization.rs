@@ -0,0 +1,125 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use ucd_parse::{
+    self, parse_missing_defaults, NormalizationProperty,
+    NormalizationPropertyValue,
+};
+
+use crate::args::ArgMatches;
+use crate::error::Result;
+use crate::util::PropertyValues;
+
+pub fn command_quick_check(args: ArgMatches<'_>) -> Result<()> {
+    let dir = args.ucd_dir()?;
+    let propvals = PropertyValues::from_ucd_dir(&dir)?;
+    let rows: Vec<NormalizationProperty> = ucd_parse::parse(&dir)?;
+    let defaults = parse_missing_defaults::<_, NormalizationProperty>(&dir)?;
+    let property = format!("{}_QC", args.value_of("form").unwrap());
+
+    let mut by_value: BTreeMap<String, BTreeSet<u32>> = BTreeMap::new();
+    let mut assigned = BTreeSet::new();
+    for row in rows {
+        if row.property != property {
+            continue;
+        }
+        let value = match row.value {
+            NormalizationPropertyValue::Enumerated(ref value) => value,
+            _ => {
+                return err!(
+                    "expected enumerated value for {}, but got {:?}",
+                    property,
+                    row.value
+                )
+            }
+        };
+        let name = propvals.canonical(&property, value)?;
+        assigned.extend(row.codepoints.into_iter().map(|c| c.value()));
+        by_value
+            .entry(name)
+            .or_insert(BTreeSet::new())
+            .extend(row.codepoints.into_iter().map(|c| c.value()));
+    }
+
+    // Codepoints that are not listed explicitly get the value of the
+    // @missing line for this property that covers them (Yes). The @missing
+    // lines of DerivedNormalizationProps.txt name the property before the
+    // value. When several @missing lines cover the same codepoint, the last
+    // one wins.
+    let mut missing: BTreeMap<u32, String> = BTreeMap::new();
+    for (codepoints, fields) in defaults {
+        let mut parts = fields.splitn(2, ';').map(str::trim);
+        if parts.next() != Some(&*property) {
+            continue;
+        }
+        let value = match parts.next() {
+            Some(value) => propvals.canonical(&property, value)?,
+            None => return err!("missing value in @missing line: {}", fields),
+        };
+        for cp in codepoints {
+            if !assigned.contains(&cp.value()) {
+                missing.insert(cp.value(), value.clone());
+            }
+        }
+    }
+    for (cp, value) in missing {
+        by_value.entry(value).or_insert(BTreeSet::new()).insert(cp);
+    }
+
+    let mut wtr = args.writer("normalization_quick_check")?;
+    if args.is_present("enum") {
+        wtr.ranges_to_enum(args.name(), &by_value)?;
+    } else if args.is_present("rust-enum") {
+        let variants = by_value.keys().map(String::as_str).collect::<Vec<_>>();
+        wtr.ranges_to_rust_enum(args.name(), &variants, &by_value)?;
+    } else {
+        wtr.names(by_value.keys())?;
+        for (name, set) in by_value {
+            wtr.ranges(&name, &set)?;
+        }
+    }
+
+    Ok(())
+}
+
+pub fn command_nfkc_casefold(args: ArgMatches<'_>) -> Result<()> {
+    let dir = args.ucd_dir()?;
+    let rows: Vec<NormalizationProperty> = ucd_parse::parse(&dir)?;
+    let property =
+        if args.is_present("simple") { "NFKC_SCF" } else { "NFKC_CF" };
+
+    // Note that a mapping may be empty, which indicates that the codepoint
+    // is removed entirely (e.g., default ignorable codepoints). Codepoints
+    // that are not listed map to themselves.
+    let mut map: BTreeMap<u32, Vec<u32>> = BTreeMap::new();
+    for row in rows {
+        if row.property != property {
+            continue;
+        }
+        let mapping = match row.value {
+            NormalizationPropertyValue::Mapping(ref cps) => {
+                cps.iter().map(|cp| cp.value()).collect::<Vec<u32>>()
+            }
+            _ => {
+                return err!(
+                    "expected codepoint sequence for {}, but got {:?}",
+                    property,
+                    row.value
+                )
+            }
+        };
+        for cp in row.codepoints {
+            map.insert(cp.value(), mapping.clone());
+        }
+    }
+    if map.is_empty() {
+        return err!(
+            "no {} mappings found in DerivedNormalizationProps.txt",
+            property
+        );
+    }
+
+    let mut wtr = args.writer("nfkc_casefold")?;
+    let flat = args.is_present("flat-table");
+    wtr.codepoint_to_codepoints(args.name(), &map, flat)?;
+    Ok(())
+}
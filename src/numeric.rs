@@ -0,0 +1,75 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use ucd_parse::extracted::{DerivedNumericType, DerivedNumericValues};
+use ucd_parse::{self, UnicodeDataNumeric};
+
+use crate::args::ArgMatches;
+use crate::error::Result;
+use crate::util::PropertyValues;
+
+pub fn command(args: ArgMatches<'_>) -> Result<()> {
+    let dir = args.ucd_dir()?;
+    let propvals = PropertyValues::from_ucd_dir(&dir)?;
+    let types: Vec<DerivedNumericType> = ucd_parse::parse(&dir)?;
+    let values: Vec<DerivedNumericValues> = ucd_parse::parse(&dir)?;
+
+    // Codepoints that are not listed in DerivedNumericType.txt have a
+    // Numeric_Type of None, so we don't bother including them.
+    let mut by_type: BTreeMap<String, BTreeSet<u32>> = BTreeMap::new();
+    for x in &types {
+        let name = propvals.canonical("nt", &x.numeric_type)?;
+        by_type
+            .entry(name)
+            .or_insert(BTreeSet::new())
+            .extend(x.codepoints.into_iter().map(|c| c.value()));
+    }
+
+    let mut by_value: BTreeMap<u32, (i64, u64)> = BTreeMap::new();
+    for x in &values {
+        let (num, den) = match x.numeric_value_fraction.parse()? {
+            UnicodeDataNumeric::Integer(n) => (n, 1),
+            UnicodeDataNumeric::Rational(n, d) if d > 0 => (n, d as u64),
+            UnicodeDataNumeric::Rational(_, _) => {
+                return err!(
+                    "invalid numeric value: {}",
+                    x.numeric_value_fraction
+                )
+            }
+        };
+        for cp in x.codepoints {
+            by_value.insert(cp.value(), (num, den));
+        }
+    }
+
+    let mut wtr = args.writer("numeric")?;
+    if args.is_present("decimal-only") {
+        let decimal = propvals.canonical("nt", "de")?;
+        let mut digits: BTreeMap<u32, u64> = BTreeMap::new();
+        for &cp in by_type.get(&decimal).into_iter().flatten() {
+            match by_value.get(&cp) {
+                Some(&(n, 1)) if (0..=9).contains(&n) => {
+                    digits.insert(cp, n as u64);
+                }
+                value => {
+                    return err!(
+                        "invalid decimal digit value for U+{:04X}: {:?}",
+                        cp,
+                        value
+                    )
+                }
+            }
+        }
+        wtr.ranges_to_unsigned_integer(args.name(), &digits)?;
+        return Ok(());
+    }
+
+    let type_name = format!("{}_TYPE", args.name());
+    if args.is_present("rust-enum") {
+        let variants = by_type.keys().map(String::as_str).collect::<Vec<_>>();
+        wtr.ranges_to_rust_enum(&type_name, &variants, &by_type)?;
+    } else {
+        wtr.ranges_to_enum(&type_name, &by_type)?;
+    }
+    wtr.codepoint_to_rational(&format!("{}_VALUE", args.name()), &by_value)?;
+    Ok(())
+}
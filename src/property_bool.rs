@@ -56,11 +56,11 @@ fn parse_properties<P: AsRef<Path>>(
 ) -> Result<BTreeMap<String, BTreeSet<u32>>> {
     // TODO: PropList.txt and DerivedCoreProperties.txt cover the majority
     // of boolean properties, but UAX44 S5.3 Table 9 lists a smattering of
-    // others that we should include here as well. (Some will need support in
-    // ucd-parse, for example, the ones found in DerivedNormalizationProps.txt
-    // while others, like Bidi_Mirrored, are derived from UnicodeData.txt.
-    // Even still, others like Composition_Exclusion have their own file
-    // (CompositionExclusions.txt).
+    // others that we should include here as well. (Some, like the ones found
+    // in DerivedNormalizationProps.txt, can be read with ucd-parse's
+    // NormalizationProperty, while others, like Bidi_Mirrored, are derived
+    // from UnicodeData.txt. Even still, others like Composition_Exclusion
+    // have their own file (CompositionExclusions.txt).
 
     let mut by_name: BTreeMap<String, BTreeSet<u32>> = BTreeMap::new();
 
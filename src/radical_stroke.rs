@@ -0,0 +1,106 @@
+use std::collections::BTreeMap;
+
+use ucd_parse::{self, CJKRadical, RadicalStroke};
+
+use crate::args::ArgMatches;
+use crate::error::Result;
+
+pub fn command(args: ArgMatches<'_>) -> Result<()> {
+    let dir = args.ucd_dir()?;
+    let radicals: Vec<CJKRadical> = ucd_parse::parse(&dir)?;
+    let records = ucd_parse::parse_unihan(&dir)?;
+
+    let mut radical_ideographs: BTreeMap<String, u32> = BTreeMap::new();
+    for x in &radicals {
+        radical_ideographs.insert(x.radical_number(), x.ideograph.value());
+    }
+
+    // The radical-stroke table only uses the first radical-stroke count of
+    // each codepoint, since that is the one used to order the code charts.
+    // The index, however, lists each codepoint under every radical it is
+    // counted under, ordered by residual strokes.
+    let mut radical_strokes: BTreeMap<u32, u64> = BTreeMap::new();
+    let mut total_strokes: BTreeMap<u32, u64> = BTreeMap::new();
+    let mut index: BTreeMap<String, Vec<(i8, u32)>> = BTreeMap::new();
+    for x in records {
+        let x = x?;
+        let cp = x.codepoint.value();
+        if let Some(counts) = x.radical_strokes()? {
+            for (i, rs) in counts.iter().enumerate() {
+                let radical = rs.radical_number();
+                if !radical_ideographs.contains_key(&radical) {
+                    return err!(
+                        "radical {} of U+{:04X} is not listed in \
+                         CJKRadicals.txt",
+                        radical,
+                        cp
+                    );
+                }
+                if i == 0 {
+                    radical_strokes.insert(cp, pack_radical_stroke(rs));
+                }
+                index.entry(radical).or_insert(vec![]).push((rs.strokes, cp));
+            }
+        }
+        if let Some(counts) = x.total_strokes()? {
+            if let Some(&count) = counts.first() {
+                total_strokes.insert(cp, count as u64);
+            }
+        }
+    }
+    let index: BTreeMap<String, Vec<u32>> = index
+        .into_iter()
+        .map(|(radical, mut cps)| {
+            cps.sort();
+            (radical, cps.into_iter().map(|(_, cp)| cp).collect())
+        })
+        .collect();
+
+    let mut wtr = args.writer("radical_stroke")?;
+    wtr.ranges_to_unsigned_integer(args.name(), &radical_strokes)?;
+    wtr.ranges_to_unsigned_integer(
+        &format!("{}_TOTAL_STROKES", args.name()),
+        &total_strokes,
+    )?;
+    wtr.string_to_codepoint(
+        &format!("{}_RADICALS", args.name()),
+        &radical_ideographs,
+    )?;
+    wtr.string_to_codepoints(&format!("{}_INDEX", args.name()), &index)?;
+    Ok(())
+}
+
+/// Pack a radical-stroke count into an integer, where bits 16-23 are the
+/// radical number, bits 8-15 are the number of apostrophes following it and
+/// bits 0-7 are the residual strokes as a two's complement signed 8-bit
+/// integer.
+///
+/// Residual stroke counts that do not fit in a signed 8-bit integer are
+/// rejected with an error when the Unihan data is parsed.
+fn pack_radical_stroke(rs: &RadicalStroke) -> u64 {
+    let strokes = u8::from_le_bytes(rs.strokes.to_le_bytes());
+    (u64::from(rs.radical) << 16)
+        | (u64::from(rs.simplified) << 8)
+        | u64::from(strokes)
+}
+
+#[cfg(test)]
+mod tests {
+    use ucd_parse::RadicalStroke;
+
+    use super::pack_radical_stroke;
+
+    #[test]
+    fn pack_negative_strokes() {
+        let rs = RadicalStroke { radical: 9, simplified: 1, strokes: -1 };
+        let packed = pack_radical_stroke(&rs);
+        assert_eq!(packed, 0x0901FF);
+        assert_eq!((packed >> 16) as u8, 9);
+        assert_eq!((packed >> 8) as u8, 1);
+        assert_eq!(i8::from_le_bytes([packed as u8]), -1);
+
+        let rs = RadicalStroke { radical: 214, simplified: 0, strokes: -128 };
+        let packed = pack_radical_stroke(&rs);
+        assert_eq!(i8::from_le_bytes([packed as u8]), -128);
+    }
+}
@@ -0,0 +1,64 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::args::ArgMatches;
+use crate::error::Result;
+
+pub fn command(args: ArgMatches<'_>) -> Result<()> {
+    let dir = args.ucd_dir()?;
+    let records = ucd_parse::parse_unihan(&dir)?;
+
+    if args.is_present("list-fields") {
+        let mut fields = BTreeSet::new();
+        for x in records {
+            fields.insert(x?.field);
+        }
+        for field in fields {
+            println!("{}", field);
+        }
+        return Ok(());
+    }
+
+    let field = match args.value_of("field") {
+        Some(field) => field,
+        None => return err!("a Unihan field must be given with --field"),
+    };
+    let mut map: BTreeMap<u32, String> = BTreeMap::new();
+    for x in records {
+        let x = x?;
+        if x.field == field {
+            map.insert(x.codepoint.value(), x.value);
+        }
+    }
+    if map.is_empty() {
+        return err!("no values found for Unihan field {}", field);
+    }
+
+    // The default table name is derived from the field, e.g., kTotalStrokes
+    // becomes TOTAL_STROKES. So only use the --name flag if it was given
+    // explicitly.
+    let table_name = if args.occurrences_of("name") > 0 {
+        args.name().to_string()
+    } else {
+        field_table_name(field)
+    };
+
+    let mut wtr = args.writer("unihan")?;
+    wtr.codepoint_to_string(&table_name, &map, true)?;
+    Ok(())
+}
+
+/// Converts the name of a Unihan field to a table name by dropping its `k`
+/// prefix and converting it from camel case to upper snake case.
+fn field_table_name(field: &str) -> String {
+    let field = field.strip_prefix('k').unwrap_or(field);
+    let mut name = String::new();
+    let mut prev_lower = false;
+    for c in field.chars() {
+        if c.is_ascii_uppercase() && prev_lower {
+            name.push('_');
+        }
+        prev_lower = c.is_ascii_lowercase();
+        name.push(c.to_ascii_uppercase());
+    }
+    name
+}
@@ -0,0 +1,73 @@
+use std::collections::BTreeMap;
+
+use ucd_parse::{
+    self, EmojiVariationSequence, ShapingEnvironment, StandardizedVariant,
+};
+
+use crate::args::ArgMatches;
+use crate::error::Result;
+
+pub fn command(args: ArgMatches<'_>) -> Result<()> {
+    let dir = args.ucd_dir()?;
+    let variants: Vec<StandardizedVariant> = ucd_parse::parse(&dir)?;
+
+    let mut map = BTreeMap::new();
+    for x in variants {
+        map.insert(
+            (x.base.value(), x.selector.value()),
+            (
+                x.description,
+                x.environments.iter().map(|env| env.as_str()).collect(),
+            ),
+        );
+    }
+
+    if !args.is_present("no-emoji") {
+        // Since emoji-variation-sequences.txt isn't part of the normal UCD
+        // download for older releases, don't die if it doesn't exist.
+        let emoji: Vec<EmojiVariationSequence> = match ucd_parse::parse(&dir) {
+            Ok(rows) => rows,
+            Err(err) => match *err.kind() {
+                ucd_parse::ErrorKind::Io(_) => {
+                    eprintln!(
+                        "{}. skipping emoji variation sequences. \
+                         emoji-variation-sequences.txt is included in \
+                         UCD 13.0.0 and newer, and can be downloaded from \
+                         https://unicode.org/Public/emoji/ for older \
+                         releases.",
+                        err,
+                    );
+                    vec![]
+                }
+                _ => return Err(From::from(err)),
+            },
+        };
+        for x in emoji {
+            map.insert(
+                (x.base.value(), x.selector.value()),
+                (x.description, vec![]),
+            );
+        }
+    }
+
+    let environments = [
+        ShapingEnvironment::Isolate,
+        ShapingEnvironment::Initial,
+        ShapingEnvironment::Medial,
+        ShapingEnvironment::Final,
+    ];
+    let environments: Vec<&str> =
+        environments.iter().map(|env| env.as_str()).collect();
+    let mut wtr = args.writer("variation_sequences")?;
+    wtr.variation_sequences(
+        args.name(),
+        &map,
+        args.is_present("descriptions"),
+        if args.is_present("environments") {
+            Some(&environments)
+        } else {
+            None
+        },
+    )?;
+    Ok(())
+}
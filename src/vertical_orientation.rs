@@ -0,0 +1,57 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use ucd_parse::{self, VerticalOrientation};
+
+use crate::args::ArgMatches;
+use crate::error::Result;
+use crate::util::PropertyValues;
+
+pub fn command(args: ArgMatches<'_>) -> Result<()> {
+    let dir = args.ucd_dir()?;
+    let propvals = PropertyValues::from_ucd_dir(&dir)?;
+    let rows: Vec<VerticalOrientation> = ucd_parse::parse(&dir)?;
+    let defaults =
+        ucd_parse::parse_missing_defaults::<_, VerticalOrientation>(&dir)?;
+
+    let mut by_value: BTreeMap<String, BTreeSet<u32>> = BTreeMap::new();
+    let mut assigned = BTreeSet::new();
+    for row in rows {
+        let value = propvals.canonical("vo", &row.orientation)?;
+        assigned.extend(row.codepoints.into_iter().map(|c| c.value()));
+        by_value
+            .entry(value)
+            .or_insert(BTreeSet::new())
+            .extend(row.codepoints.into_iter().map(|c| c.value()));
+    }
+
+    // Codepoints that are not listed explicitly get the value of the
+    // @missing line that covers them. When several @missing lines cover the
+    // same codepoint, the last one wins.
+    let mut missing: BTreeMap<u32, String> = BTreeMap::new();
+    for (codepoints, value) in defaults {
+        let value = propvals.canonical("vo", &value)?;
+        for cp in codepoints {
+            if !assigned.contains(&cp.value()) {
+                missing.insert(cp.value(), value.clone());
+            }
+        }
+    }
+    for (cp, value) in missing {
+        by_value.entry(value).or_insert(BTreeSet::new()).insert(cp);
+    }
+
+    let mut wtr = args.writer("vertical_orientation")?;
+    if args.is_present("enum") {
+        wtr.ranges_to_enum(args.name(), &by_value)?;
+    } else if args.is_present("rust-enum") {
+        let variants = by_value.keys().map(String::as_str).collect::<Vec<_>>();
+        wtr.ranges_to_rust_enum(args.name(), &variants, &by_value)?;
+    } else {
+        wtr.names(by_value.keys())?;
+        for (name, set) in by_value {
+            wtr.ranges(&name, &set)?;
+        }
+    }
+
+    Ok(())
+}
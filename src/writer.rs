@@ -20,6 +20,28 @@ use crate::util;
 #[derive(Clone, Debug)]
 pub struct WriterBuilder(WriterOptions);
 
+/// A condition of a conditional case mapping, which is written as the
+/// corresponding variant of the emitted `CasingCondition` enum.
+///
+/// The argument of a `Context` or `NotContext` condition is the name of one of
+/// the casing contexts given to `casing_condition_types`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CasingCondition {
+    /// The mapping applies to the language with the given identifier.
+    Language(String),
+    /// The mapping applies when the given casing context holds.
+    Context(String),
+    /// The mapping applies when the given casing context does not hold.
+    NotContext(String),
+    /// A condition that is not otherwise recognized.
+    Other(String),
+}
+
+/// An entry of a table written by `codepoint_to_conditional_codepoints`: a
+/// codepoint, the conditions under which its mapping applies and the mapping
+/// itself.
+pub type ConditionalMapping = (u32, Vec<CasingCondition>, Vec<u32>);
+
 #[derive(Clone, Debug)]
 struct WriterOptions {
     name: String,
@@ -149,7 +171,31 @@ impl Writer {
             let charty = self.rust_codepoint_type();
             format!("&'static [({}, {})]", charty, charty)
         };
+        self.names_with_type(names, &ty)
+    }
+
+    /// Write a sorted sequence of string names that map to the sets of
+    /// strings written by `strings`.
+    pub fn string_set_names<I: IntoIterator<Item = T>, T: AsRef<str>>(
+        &mut self,
+        names: I,
+    ) -> Result<()> {
+        self.header()?;
+        self.separator()?;
+
+        let ty = if self.opts.fst_dir.is_some() {
+            "::fst::Set<&'static [u8]>"
+        } else {
+            "&'static [&'static str]"
+        };
+        self.names_with_type(names, ty)
+    }
 
+    fn names_with_type<I: IntoIterator<Item = T>, T: AsRef<str>>(
+        &mut self,
+        names: I,
+        ty: &str,
+    ) -> Result<()> {
         let mut names: Vec<String> =
             names.into_iter().map(|name| name.as_ref().to_string()).collect();
         names.sort();
@@ -200,6 +246,40 @@ impl Writer {
         Ok(())
     }
 
+    /// Write a sorted sequence of strings.
+    ///
+    /// If the output format is an FST, then the strings are stored as a set
+    /// of their UTF-8 encodings. Otherwise, a sorted slice of string literals
+    /// is emitted, which is suitable for binary search.
+    pub fn strings(
+        &mut self,
+        name: &str,
+        strings: &BTreeSet<String>,
+    ) -> Result<()> {
+        self.header()?;
+        self.separator()?;
+
+        let name = rust_const_name(name);
+        if self.opts.fst_dir.is_some() {
+            let mut builder = SetBuilder::memory();
+            builder.extend_iter(strings.iter().map(|s| s.as_bytes()))?;
+            let set = builder.into_set();
+            self.fst(&name, set.as_fst(), false)?;
+        } else {
+            writeln!(
+                self.wtr,
+                "pub const {}: &'static [&'static str] = &[",
+                name
+            )?;
+            for s in strings {
+                self.wtr.write_str(&format!("{:?}, ", s))?;
+            }
+            writeln!(self.wtr, "];")?;
+        }
+        self.wtr.flush()?;
+        Ok(())
+    }
+
     fn ranges_slice(
         &mut self,
         name: &str,
@@ -479,6 +559,38 @@ impl Writer {
         Ok(())
     }
 
+    /// Write a sorted sequence of codepoint ranges, each associated with a
+    /// string.
+    ///
+    /// A slice of `(start, end, string)` triples is emitted regardless of the
+    /// output format.
+    pub fn ranges_to_string(
+        &mut self,
+        name: &str,
+        table: &[(u32, u32, String)],
+    ) -> Result<()> {
+        self.header()?;
+        self.separator()?;
+
+        let name = rust_const_name(name);
+        let ty = self.rust_codepoint_type();
+        writeln!(
+            self.wtr,
+            "pub const {}: &'static [({}, {}, &'static str)] = &[",
+            name, ty, ty
+        )?;
+        for &(start, end, ref s) in table {
+            let range = (self.rust_codepoint(start), self.rust_codepoint(end));
+            if let (Some(start), Some(end)) = range {
+                let src = format!("({}, {}, {:?}), ", start, end, s);
+                self.wtr.write_str(&src)?;
+            }
+        }
+        writeln!(self.wtr, "];")?;
+        self.wtr.flush()?;
+        Ok(())
+    }
+
     /// Write a map that associates strings to strings.
     ///
     /// The only supported output format is a sorted slice, which can be
@@ -648,6 +760,338 @@ impl Writer {
         Ok(())
     }
 
+    /// Write a map that associates bracket codepoints with their paired
+    /// bracket and whether they are an opening or a closing bracket.
+    ///
+    /// A `BidiPairedBracketType` enum is emitted along with the table. Each
+    /// entry in the table is the bracket, its paired bracket and its type,
+    /// where the type is the name of a variant of the enum (`Open` or
+    /// `Close`).
+    ///
+    /// This does not support the FST format.
+    pub fn codepoint_to_bracket(
+        &mut self,
+        name: &str,
+        map: &BTreeMap<u32, (u32, &str)>,
+    ) -> Result<()> {
+        if self.opts.fst_dir.is_some() {
+            return err!("cannot emit codepoint->bracket map as an FST");
+        }
+
+        self.header()?;
+        self.separator()?;
+        self.bracket_type_enum()?;
+
+        let name = rust_const_name(name);
+        let ty = self.rust_codepoint_type();
+        writeln!(
+            self.wtr,
+            "pub const {}: &'static [({}, {}, BidiPairedBracketType)] = &[",
+            name, ty, ty
+        )?;
+        for (&cp, &(paired, kind)) in map {
+            let (cp, paired) =
+                match (self.rust_codepoint(cp), self.rust_codepoint(paired)) {
+                    (Some(cp), Some(paired)) => (cp, paired),
+                    _ => continue,
+                };
+            self.wtr.write_str(&format!(
+                "({}, {}, BidiPairedBracketType::{}), ",
+                cp, paired, kind
+            ))?;
+        }
+        writeln!(self.wtr, "];")?;
+        self.wtr.flush()?;
+        Ok(())
+    }
+
+    /// Write a function that associates bracket codepoints with their paired
+    /// bracket and whether they are an opening or a closing bracket.
+    ///
+    /// The function will use a match expression on the codepoint. The
+    /// fallback branch of the match returns `None`.
+    pub fn codepoint_to_bracket_fn(
+        &mut self,
+        name: &str,
+        map: &BTreeMap<u32, (u32, &str)>,
+    ) -> Result<()> {
+        self.header()?;
+        self.separator()?;
+        self.bracket_type_enum()?;
+
+        let fn_name = rust_fn_name(name);
+        let ty = self.rust_codepoint_type();
+        writeln!(
+            self.wtr,
+            "pub fn {}(cp: {}) -> Option<({}, BidiPairedBracketType)> {{",
+            fn_name, ty, ty
+        )?;
+        self.wtr.indent("    ");
+        self.wtr.write_str("match cp {")?;
+        self.wtr.flush_line()?;
+        self.wtr.indent("        ");
+        for (&cp, &(paired, kind)) in map {
+            let (cp, paired) =
+                match (self.rust_codepoint(cp), self.rust_codepoint(paired)) {
+                    (Some(cp), Some(paired)) => (cp, paired),
+                    _ => continue,
+                };
+            self.wtr.write_str(&format!(
+                "{} => Some(({}, BidiPairedBracketType::{})),",
+                cp, paired, kind
+            ))?;
+            self.wtr.flush_line()?;
+        }
+        self.wtr.write_str("_ => None,")?;
+        self.wtr.flush_line()?;
+        self.wtr.indent("    ");
+        self.wtr.write_str("}")?;
+        self.wtr.flush_line()?;
+        writeln!(self.wtr, "}}")?;
+        self.wtr.flush()?;
+        Ok(())
+    }
+
+    fn bracket_type_enum(&mut self) -> Result<()> {
+        writeln!(
+            self.wtr,
+            "#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]",
+        )?;
+        writeln!(self.wtr, "pub enum BidiPairedBracketType {{")?;
+        for kind in &["Open", "Close"] {
+            self.wtr.write_str(&format!("{}, ", kind))?;
+        }
+        writeln!(self.wtr, "}}\n")?;
+        Ok(())
+    }
+
+    /// Write a sorted table of variation sequences, where each sequence is a
+    /// pair of a base codepoint and a variation selector.
+    ///
+    /// When `descriptions` is true, each entry also includes the description
+    /// of the sequence. When `environments` is given, each entry also
+    /// includes the names of the shaping environments in which the sequence
+    /// applies (where an empty list means all environments), and a
+    /// `ShapingEnvironment` enum is emitted with a variant for each of the
+    /// given environment names.
+    ///
+    /// When the output format is an FST, then neither column may be
+    /// requested. The FST emitted is a set whose keys are the concatenation
+    /// of the base codepoint and the selector, each encoded as a 32-bit big
+    /// endian unsigned integer.
+    pub fn variation_sequences(
+        &mut self,
+        name: &str,
+        map: &BTreeMap<(u32, u32), (String, Vec<&str>)>,
+        descriptions: bool,
+        environments: Option<&[&str]>,
+    ) -> Result<()> {
+        if self.opts.fst_dir.is_some()
+            && (descriptions || environments.is_some())
+        {
+            return err!(
+                "cannot emit variation sequence descriptions or shaping \
+                 environments as an FST"
+            );
+        }
+
+        self.header()?;
+        self.separator()?;
+
+        let name = rust_const_name(name);
+        if self.opts.fst_dir.is_some() {
+            let mut builder = SetBuilder::memory();
+            builder.extend_iter(
+                map.keys().map(|&(base, sel)| u32_pair_key(base, sel)),
+            )?;
+            let set = builder.into_set();
+            self.fst(&name, set.as_fst(), false)?;
+            self.wtr.flush()?;
+            return Ok(());
+        }
+
+        if let Some(all) = environments {
+            writeln!(
+                self.wtr,
+                "#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]",
+            )?;
+            writeln!(self.wtr, "pub enum ShapingEnvironment {{")?;
+            for env in all {
+                let variant = rust_type_name(env);
+                self.wtr.write_str(&format!("{}, ", variant))?;
+            }
+            writeln!(self.wtr, "}}\n")?;
+        }
+
+        let ty = self.rust_codepoint_type();
+        let mut columns = vec![ty, ty];
+        if descriptions {
+            columns.push("&'static str");
+        }
+        if environments.is_some() {
+            columns.push("&'static [ShapingEnvironment]");
+        }
+        writeln!(
+            self.wtr,
+            "pub const {}: &'static [({})] = &[",
+            name,
+            columns.join(", ")
+        )?;
+        for (&(base, sel), (desc, envs)) in map {
+            let (base, sel) =
+                match (self.rust_codepoint(base), self.rust_codepoint(sel)) {
+                    (Some(base), Some(sel)) => (base, sel),
+                    _ => continue,
+                };
+            let mut fields = vec![base, sel];
+            if descriptions {
+                fields.push(format!("{:?}", desc));
+            }
+            if environments.is_some() {
+                let envs: Vec<String> = envs
+                    .iter()
+                    .map(|env| {
+                        format!("ShapingEnvironment::{}", rust_type_name(env))
+                    })
+                    .collect();
+                fields.push(format!("&[{}]", envs.join(", ")));
+            }
+            self.wtr.write_str(&format!("({}), ", fields.join(", ")))?;
+        }
+        writeln!(self.wtr, "];")?;
+        self.wtr.flush()?;
+        Ok(())
+    }
+
+    /// Write a map that associates codepoints with rational numbers, where
+    /// each rational number is given as a numerator and a (positive)
+    /// denominator.
+    ///
+    /// This does not support the FST format.
+    pub fn codepoint_to_rational(
+        &mut self,
+        name: &str,
+        map: &BTreeMap<u32, (i64, u64)>,
+    ) -> Result<()> {
+        if self.opts.fst_dir.is_some() {
+            return err!("cannot emit codepoint->rational map as an FST");
+        }
+
+        self.header()?;
+        self.separator()?;
+
+        let name = rust_const_name(name);
+        let ty = self.rust_codepoint_type();
+        writeln!(
+            self.wtr,
+            "pub const {}: &'static [({}, i64, u64)] = &[",
+            name, ty
+        )?;
+        for (&cp, &(num, den)) in map {
+            if let Some(cp) = self.rust_codepoint(cp) {
+                self.wtr
+                    .write_str(&format!("({}, {}, {}), ", cp, num, den))?;
+            }
+        }
+        writeln!(self.wtr, "];")?;
+        self.wtr.flush()?;
+        Ok(())
+    }
+
+    /// Write a map that associates pairs of codepoints with a single
+    /// codepoint.
+    ///
+    /// This supports the FST format in addition to the standard sorted slice
+    /// format. When using an FST, each key is the concatenation of the two
+    /// codepoints in the pair, each encoded as a 32-bit big endian unsigned
+    /// integer. Values are 32-bit unsigned integers.
+    pub fn codepoint_pair_to_codepoint(
+        &mut self,
+        name: &str,
+        map: &BTreeMap<(u32, u32), u32>,
+    ) -> Result<()> {
+        self.header()?;
+        self.separator()?;
+
+        let name = rust_const_name(name);
+        if self.opts.fst_dir.is_some() {
+            let mut builder = MapBuilder::memory();
+            for (&(k1, k2), &v) in map {
+                builder.insert(u32_pair_key(k1, k2), v as u64)?;
+            }
+            let map = builder.into_map();
+            self.fst(&name, map.as_fst(), true)?;
+        } else {
+            let ty = self.rust_codepoint_type();
+            writeln!(
+                self.wtr,
+                "pub const {}: &'static [(({}, {}), {})] = &[",
+                name, ty, ty, ty
+            )?;
+            for (&(k1, k2), &v) in map {
+                let (k1, k2, v) = match (
+                    self.rust_codepoint(k1),
+                    self.rust_codepoint(k2),
+                    self.rust_codepoint(v),
+                ) {
+                    (Some(k1), Some(k2), Some(v)) => (k1, k2, v),
+                    _ => continue,
+                };
+                self.wtr.write_str(&format!("(({}, {}), {}), ", k1, k2, v))?;
+            }
+            writeln!(self.wtr, "];")?;
+        }
+        self.wtr.flush()?;
+        Ok(())
+    }
+
+    /// Write a function that associates pairs of codepoints with a single
+    /// codepoint.
+    ///
+    /// The function will use a match expression on the pair. The fallback
+    /// branch of the match returns `None`.
+    pub fn codepoint_pair_to_codepoint_fn(
+        &mut self,
+        name: &str,
+        map: &BTreeMap<(u32, u32), u32>,
+    ) -> Result<()> {
+        self.header()?;
+        self.separator()?;
+
+        let fn_name = rust_fn_name(name);
+        let ty = self.rust_codepoint_type();
+        writeln!(
+            self.wtr,
+            "pub fn {}(cp1: {}, cp2: {}) -> Option<{}> {{",
+            fn_name, ty, ty, ty
+        )?;
+        self.wtr.indent("    ");
+        self.wtr.write_str("match (cp1, cp2) {")?;
+        self.wtr.flush_line()?;
+        self.wtr.indent("        ");
+        for (&(k1, k2), &v) in map {
+            let (k1, k2, v) = match (
+                self.rust_codepoint(k1),
+                self.rust_codepoint(k2),
+                self.rust_codepoint(v),
+            ) {
+                (Some(k1), Some(k2), Some(v)) => (k1, k2, v),
+                _ => continue,
+            };
+            self.wtr
+                .write_str(&format!("({}, {}) => Some({}),", k1, k2, v))?;
+            self.wtr.flush_line()?;
+        }
+        self.wtr.write_str("_ => None,")?;
+        self.wtr.flush_line()?;
+        self.wtr.indent("    ");
+        self.wtr.write_str("}")?;
+        self.wtr.flush_line()?;
+        writeln!(self.wtr, "}}")?;
+        self.wtr.flush()?;
+        Ok(())
+    }
+
     /// Write a map that associates codepoints with other codepoints, where
     /// each codepoint can be associated with possibly many other codepoints.
     ///
@@ -673,6 +1117,10 @@ impl Writer {
     /// Write a map that associates codepoints with a sequence of other
     /// codepoints.
     ///
+    /// When `emit_flat_table` is true, every sequence is padded to the length
+    /// of the longest sequence in the map, so that values can be emitted as
+    /// fixed size arrays.
+    ///
     /// This does not support the FST format.
     pub fn codepoint_to_codepoints(
         &mut self,
@@ -689,6 +1137,7 @@ impl Writer {
 
         let name = rust_const_name(name);
         let ty = self.rust_codepoint_type();
+        let flat_width = map.values().map(|vs| vs.len()).max().unwrap_or(0);
         if !emit_flat_table {
             writeln!(
                 self.wtr,
@@ -698,8 +1147,8 @@ impl Writer {
         } else {
             writeln!(
                 self.wtr,
-                "pub const {}: &'static [({}, [{}; 3])] = &[",
-                name, ty, ty
+                "pub const {}: &'static [({}, [{}; {}])] = &[",
+                name, ty, ty, flat_width
             )?;
         }
         'LOOP: for (&k, vs) in map {
@@ -711,13 +1160,6 @@ impl Writer {
             };
 
             let (padded_vs, slice_prefix) = if emit_flat_table {
-                // These checks are for future-proofing and cannot be hit currently.
-                if vs.len() > 3 {
-                    return err!(
-                        "flat-table representation cannot be used when value \
-                         arrays may contain more than 3 entries"
-                    );
-                }
                 let flat_padding =
                     if self.opts.char_literals { 0 } else { !0 };
                 if vs.contains(&flat_padding) {
@@ -731,7 +1173,7 @@ impl Writer {
                     .iter()
                     .copied()
                     .chain(std::iter::repeat(flat_padding))
-                    .take(3)
+                    .take(flat_width)
                     .collect::<Vec<_>>();
                 (res, "")
             } else {
@@ -761,24 +1203,168 @@ impl Writer {
         Ok(())
     }
 
-    /// Write a map that associates codepoints to strings.
+    /// Write the type definitions for the conditions used in tables emitted
+    /// by `codepoint_to_conditional_codepoints`.
     ///
-    /// When the output format is an FST, then the FST map emitted is from
-    /// codepoint to u64, where the string is encoded into the u64. The least
-    /// significant byte of the u64 corresponds to the first byte in the
-    /// string. The end of a string is delimited by the zero byte. If a string
-    /// is more than 8 bytes or contains a `NUL` byte, then an error is
-    /// returned.
-    pub fn codepoint_to_string(
-        &mut self,
-        name: &str,
+    /// Each of the given contexts becomes a variant of the `CasingContext`
+    /// enum. This should be called once before writing any such tables.
+    pub fn casing_condition_types(&mut self, contexts: &[&str]) -> Result<()> {
+        self.header()?;
+        self.separator()?;
+
+        writeln!(
+            self.wtr,
+            "#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]",
+        )?;
+        writeln!(self.wtr, "pub enum CasingContext {{")?;
+        for context in contexts {
+            let variant = rust_type_name(context);
+            self.wtr.write_str(&format!("{}, ", variant))?;
+        }
+        writeln!(self.wtr, "}}\n")?;
+
+        writeln!(
+            self.wtr,
+            "#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]",
+        )?;
+        writeln!(self.wtr, "pub enum CasingCondition {{")?;
+        self.wtr.write_str("Language(&'static str), ")?;
+        self.wtr.write_str("Context(CasingContext), ")?;
+        self.wtr.write_str("NotContext(CasingContext), ")?;
+        self.wtr.write_str("Other(&'static str), ")?;
+        writeln!(self.wtr, "}}")?;
+        self.wtr.flush()?;
+        Ok(())
+    }
+
+    /// Write a table that associates codepoints with a sequence of other
+    /// codepoints, subject to a list of conditions.
+    ///
+    /// Each entry of the table is a codepoint, the conditions that must all
+    /// hold for the mapping to apply and the (possibly empty) mapping itself.
+    /// The table is emitted in the order given, which permits multiple entries
+    /// for the same codepoint. The types of the conditions are written by
+    /// `casing_condition_types`.
+    ///
+    /// This does not support the FST format.
+    pub fn codepoint_to_conditional_codepoints(
+        &mut self,
+        name: &str,
+        table: &[ConditionalMapping],
+    ) -> Result<()> {
+        if self.opts.fst_dir.is_some() {
+            return err!(
+                "cannot emit conditional codepoint->codepoints map as an FST"
+            );
+        }
+
+        self.header()?;
+        self.separator()?;
+
+        let name = rust_const_name(name);
+        let ty = self.rust_codepoint_type();
+        writeln!(
+            self.wtr,
+            "pub const {}: &'static [({}, &'static [CasingCondition], \
+             &'static [{}])] = &[",
+            name, ty, ty
+        )?;
+        'LOOP: for &(k, ref conditions, ref vs) in table {
+            let kstr = match self.rust_codepoint(k) {
+                None => continue 'LOOP,
+                Some(k) => k,
+            };
+            let mut vstrs = vec![];
+            for &v in vs {
+                match self.rust_codepoint(v) {
+                    None => continue 'LOOP,
+                    Some(v) => vstrs.push(v),
+                }
+            }
+            let cstrs: Vec<String> = conditions
+                .iter()
+                .map(|condition| match *condition {
+                    CasingCondition::Language(ref lang) => {
+                        format!("CasingCondition::Language({:?})", lang)
+                    }
+                    CasingCondition::Context(ref context) => format!(
+                        "CasingCondition::Context(CasingContext::{})",
+                        rust_type_name(context)
+                    ),
+                    CasingCondition::NotContext(ref context) => format!(
+                        "CasingCondition::NotContext(CasingContext::{})",
+                        rust_type_name(context)
+                    ),
+                    CasingCondition::Other(ref other) => {
+                        format!("CasingCondition::Other({:?})", other)
+                    }
+                })
+                .collect();
+
+            self.wtr.write_str(&format!("({}, &[", kstr))?;
+            for c in cstrs {
+                self.wtr.write_str(&format!("{}, ", c))?;
+            }
+            self.wtr.write_str("], &[")?;
+            for v in vstrs {
+                self.wtr.write_str(&format!("{}, ", v))?;
+            }
+            self.wtr.write_str("]), ")?;
+        }
+        writeln!(self.wtr, "];")?;
+
+        self.wtr.flush()?;
+        Ok(())
+    }
+
+    /// Write a map that associates codepoints to strings.
+    ///
+    /// When the output format is an FST and `indexed` is false, then the FST
+    /// map emitted is from codepoint to u64, where the string is encoded into
+    /// the u64. The least significant byte of the u64 corresponds to the first
+    /// byte in the string. The end of a string is delimited by the zero byte.
+    /// If a string is more than 8 bytes or contains a `NUL` byte, then an
+    /// error is returned.
+    ///
+    /// When the output format is an FST and `indexed` is true, then the FST
+    /// maps each codepoint to an index into a side table of strings of any
+    /// length, which is emitted as a slice named `{NAME}_STRINGS`. Identical
+    /// strings are only stored once. `indexed` has no effect on other output
+    /// formats.
+    pub fn codepoint_to_string(
+        &mut self,
+        name: &str,
         map: &BTreeMap<u32, String>,
+        indexed: bool,
     ) -> Result<()> {
         self.header()?;
         self.separator()?;
 
         let name = rust_const_name(name);
-        if self.opts.fst_dir.is_some() {
+        if self.opts.fst_dir.is_some() && indexed {
+            let mut strings: Vec<&str> = vec![];
+            let mut indices: BTreeMap<&str, u64> = BTreeMap::new();
+            let mut builder = MapBuilder::memory();
+            for (&k, v) in map {
+                let index = *indices.entry(v).or_insert_with(|| {
+                    strings.push(v);
+                    (strings.len() - 1) as u64
+                });
+                builder.insert(u32_key(k), index)?;
+            }
+            let map = builder.into_map();
+            self.fst(&name, map.as_fst(), true)?;
+            self.separator()?;
+            writeln!(
+                self.wtr,
+                "pub const {}_STRINGS: &'static [&'static str] = &[",
+                name
+            )?;
+            for s in strings {
+                self.wtr.write_str(&format!("{:?}, ", s))?;
+            }
+            writeln!(self.wtr, "];")?;
+        } else if self.opts.fst_dir.is_some() {
             let mut builder = MapBuilder::memory();
             for (&k, v) in map {
                 let v = pack_str(v)?;
@@ -795,6 +1381,42 @@ impl Writer {
         Ok(())
     }
 
+    /// Write a map that associates codepoints with a sequence of strings.
+    ///
+    /// This does not support the FST format.
+    pub fn codepoint_to_strings(
+        &mut self,
+        name: &str,
+        map: &BTreeMap<u32, Vec<String>>,
+    ) -> Result<()> {
+        if self.opts.fst_dir.is_some() {
+            return err!("cannot emit codepoint->strings map as an FST");
+        }
+
+        self.header()?;
+        self.separator()?;
+
+        let name = rust_const_name(name);
+        let ty = self.rust_codepoint_type();
+        writeln!(
+            self.wtr,
+            "pub const {}: &'static [({}, &'static [&'static str])] = &[",
+            name, ty
+        )?;
+        for (&cp, strings) in map {
+            if let Some(cp) = self.rust_codepoint(cp) {
+                self.wtr.write_str(&format!("({}, &[", cp))?;
+                for s in strings {
+                    self.wtr.write_str(&format!("{:?}, ", s))?;
+                }
+                self.wtr.write_str("]), ")?;
+            }
+        }
+        writeln!(self.wtr, "];")?;
+        self.wtr.flush()?;
+        Ok(())
+    }
+
     fn codepoint_to_string_slice(
         &mut self,
         name: &str,
@@ -861,6 +1483,721 @@ impl Writer {
         Ok(())
     }
 
+    /// Write a map that associates strings with a sequence of codepoints.
+    ///
+    /// When the output format is an FST, then the FST maps each string to an
+    /// index into a side table, which is emitted as a slice named
+    /// `{NAME}_CODEPOINTS`. Each element of the side table is a sequence of
+    /// codepoints. Identical sequences are only stored once.
+    pub fn string_to_codepoints(
+        &mut self,
+        name: &str,
+        map: &BTreeMap<String, Vec<u32>>,
+    ) -> Result<()> {
+        self.header()?;
+        self.separator()?;
+
+        let name = rust_const_name(name);
+        if self.opts.fst_dir.is_some() {
+            let mut sequences: Vec<&[u32]> = vec![];
+            let mut indices: BTreeMap<&[u32], u64> = BTreeMap::new();
+            let mut builder = MapBuilder::memory();
+            for (k, vs) in map {
+                let index = *indices.entry(vs).or_insert_with(|| {
+                    sequences.push(vs);
+                    (sequences.len() - 1) as u64
+                });
+                builder.insert(k.as_bytes(), index)?;
+            }
+            let fst_map = builder.into_map();
+            self.fst(&name, fst_map.as_fst(), true)?;
+            self.separator()?;
+            self.codepoint_sequences_slice(
+                &format!("{}_CODEPOINTS", name),
+                &sequences,
+            )?;
+        } else {
+            let ty = self.rust_codepoint_type();
+            writeln!(
+                self.wtr,
+                "pub const {}: &'static [(&'static str, &'static [{}])] = &[",
+                name, ty
+            )?;
+            'LOOP: for (k, vs) in map {
+                let mut vstrs = vec![];
+                for &v in vs {
+                    match self.rust_codepoint(v) {
+                        None => continue 'LOOP,
+                        Some(v) => vstrs.push(v),
+                    }
+                }
+                self.wtr.write_str(&format!("({:?}, &[", k))?;
+                for v in vstrs {
+                    self.wtr.write_str(&format!("{}, ", v))?;
+                }
+                self.wtr.write_str("]), ")?;
+            }
+            writeln!(self.wtr, "];")?;
+        }
+        self.wtr.flush()?;
+        Ok(())
+    }
+
+    fn codepoint_sequences_slice(
+        &mut self,
+        name: &str,
+        table: &[&[u32]],
+    ) -> Result<()> {
+        let ty = self.rust_codepoint_type();
+        writeln!(
+            self.wtr,
+            "pub const {}: &'static [&'static [{}]] = &[",
+            name, ty
+        )?;
+        for vs in table {
+            self.wtr.write_str("&[")?;
+            for &v in vs.iter() {
+                match self.rust_codepoint(v) {
+                    None => {
+                        return err!(
+                        "cannot represent U+{:04X} in a codepoint sequence",
+                        v
+                    )
+                    }
+                    Some(v) => self.wtr.write_str(&format!("{}, ", v))?,
+                }
+            }
+            self.wtr.write_str("], ")?;
+        }
+        writeln!(self.wtr, "];")?;
+        Ok(())
+    }
+
+    /// Write a compressed table of character names that supports looking up
+    /// names by codepoint and codepoints by name.
+    ///
+    /// Each name is split on spaces into words, and every distinct word is
+    /// stored once in `{NAME}_WORDS`, with its bounds in
+    /// `{NAME}_WORD_OFFSETS`. Words are numbered in order of decreasing
+    /// frequency. Each name is then stored in `{NAME}_DATA` as a sequence of
+    /// word numbers, each encoded as a variable-length integer (7 bits per
+    /// byte, with the high bit set on every byte except the last), so that
+    /// the most common words take a single byte.
+    ///
+    /// `{NAME}_CODEPOINTS` maps each codepoint to the offset of its name in
+    /// `{NAME}_DATA`, and `{NAME}_BY_NAME` lists the indices of its entries
+    /// in name order. Names of the same codepoint keep the order in which
+    /// they are given. Accessor functions that reconstruct names into a
+    /// caller provided buffer, and that find codepoints by name, are emitted
+    /// along with the tables.
+    ///
+    /// `algorithmic` lists ranges of codepoints whose names are generated from
+    /// a prefix, and is emitted as `{NAME}_ALGORITHMIC`. A prefix ending in
+    /// `-` is followed by the codepoint in hexadecimal (with at least four
+    /// digits), and any other prefix is followed by the short names of the
+    /// jamo of a Hangul syllable. The accessor functions fall back to these
+    /// ranges for codepoints and names that are not in the table.
+    ///
+    /// This does not support the FST format or character literals.
+    pub fn names_compressed(
+        &mut self,
+        name: &str,
+        names: &[(u32, String)],
+        algorithmic: &[(u32, u32, String)],
+    ) -> Result<()> {
+        if self.opts.fst_dir.is_some() {
+            return err!("cannot emit compressed names as an FST");
+        }
+        if self.opts.char_literals {
+            return err!("cannot emit compressed names with char literals");
+        }
+
+        self.header()?;
+        self.separator()?;
+
+        let name = rust_const_name(name);
+        let mut names = names.to_vec();
+        names.sort_by_key(|&(cp, _)| cp);
+
+        let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+        for (_, n) in &names {
+            for word in n.split(' ') {
+                if word.is_empty() {
+                    return err!("cannot compress name {:?} (empty word)", n);
+                }
+                *counts.entry(word).or_insert(0) += 1;
+            }
+        }
+        // The sort is stable, so words with equal counts stay sorted.
+        let mut words: Vec<&str> = counts.keys().cloned().collect();
+        words.sort_by_key(|w| ::std::cmp::Reverse(counts[w]));
+        let indices: BTreeMap<&str, usize> =
+            words.iter().enumerate().map(|(i, &w)| (w, i)).collect();
+
+        let mut data = vec![];
+        let mut entries = vec![];
+        let mut max_len = 0;
+        for (cp, n) in &names {
+            entries.push((*cp, data.len()));
+            for word in n.split(' ') {
+                encode_word_index(indices[word], &mut data);
+            }
+            max_len = ::std::cmp::max(max_len, n.len());
+        }
+        for &(_, end, ref prefix) in algorithmic {
+            let suffix_len = if prefix.ends_with('-') {
+                ::std::cmp::max(4, format!("{:X}", end).len())
+            } else {
+                [HANGUL_JAMO_L, HANGUL_JAMO_V, HANGUL_JAMO_T]
+                    .iter()
+                    .map(|names| names.iter().map(|n| n.len()).max().unwrap())
+                    .sum()
+            };
+            max_len = ::std::cmp::max(max_len, prefix.len() + suffix_len);
+        }
+        let mut by_name: Vec<usize> = (0..names.len()).collect();
+        by_name.sort_by_key(|&i| &names[i].1);
+
+        writeln!(
+            self.wtr,
+            "pub const {}_MAX_LEN: usize = {};",
+            name, max_len
+        )?;
+        self.separator()?;
+
+        writeln!(
+            self.wtr,
+            "pub const {}_WORDS: &'static str = concat!(",
+            name
+        )?;
+        let mut offsets = vec![0];
+        for word in &words {
+            self.wtr.write_str(&format!("{:?}, ", word))?;
+            offsets.push(offsets[offsets.len() - 1] + word.len());
+        }
+        writeln!(self.wtr, ");")?;
+        self.separator()?;
+
+        let ty = smallest_unsigned_type(offsets[offsets.len() - 1] as u64);
+        writeln!(
+            self.wtr,
+            "pub const {}_WORD_OFFSETS: &'static [{}] = &[",
+            name, ty
+        )?;
+        for offset in offsets {
+            self.wtr.write_str(&format!("{}, ", offset))?;
+        }
+        writeln!(self.wtr, "];")?;
+        self.separator()?;
+
+        writeln!(self.wtr, "pub const {}_DATA: &'static [u8] = &[", name)?;
+        self.write_slice_u8(&data)?;
+        writeln!(self.wtr, "];")?;
+        self.separator()?;
+
+        let ty = smallest_unsigned_type(data.len() as u64);
+        writeln!(
+            self.wtr,
+            "pub const {}_CODEPOINTS: &'static [(u32, {})] = &[",
+            name, ty
+        )?;
+        for (cp, offset) in entries {
+            self.wtr.write_str(&format!("({}, {}), ", cp, offset))?;
+        }
+        writeln!(self.wtr, "];")?;
+        self.separator()?;
+
+        let ty = smallest_unsigned_type(names.len() as u64);
+        writeln!(
+            self.wtr,
+            "pub const {}_BY_NAME: &'static [{}] = &[",
+            name, ty
+        )?;
+        for i in by_name {
+            self.wtr.write_str(&format!("{}, ", i))?;
+        }
+        writeln!(self.wtr, "];")?;
+        self.separator()?;
+
+        writeln!(
+            self.wtr,
+            "pub const {}_ALGORITHMIC: &'static [(u32, u32, &'static str)] = &[",
+            name
+        )?;
+        for &(start, end, ref prefix) in algorithmic {
+            let src = format!("({}, {}, {:?}), ", start, end, prefix);
+            self.wtr.write_str(&src)?;
+        }
+        writeln!(self.wtr, "];")?;
+        self.separator()?;
+
+        self.names_compressed_fns(&name)?;
+        self.wtr.indent("  ");
+        self.wtr.flush()?;
+        Ok(())
+    }
+
+    /// Write the accessor functions of a compressed names table named `name`,
+    /// which look up names by codepoint and codepoints by name.
+    fn names_compressed_fns(&mut self, name: &str) -> Result<()> {
+        let fname = rust_fn_name(name);
+
+        writeln!(
+            self.wtr,
+            "/// Write the name of the given codepoint into `buf` and return it."
+        )?;
+        writeln!(self.wtr, "///")?;
+        writeln!(
+            self.wtr,
+            "/// If the codepoint has more than one name, then the first one \
+             is returned."
+        )?;
+        writeln!(
+            self.wtr,
+            "/// Codepoints that are not in the table are looked up in \
+             `{}_ALGORITHMIC`.",
+            name
+        )?;
+        writeln!(
+            self.wtr,
+            "/// This returns `None` if the codepoint has no name or if `buf` \
+             is too small."
+        )?;
+        writeln!(
+            self.wtr,
+            "/// A buffer of `{}_MAX_LEN` bytes is always big enough.",
+            name
+        )?;
+        writeln!(
+            self.wtr,
+            "pub fn {}_lookup_name(cp: u32, buf: &mut [u8]) -> Option<&str> {{",
+            fname
+        )?;
+        self.code_line(
+            1,
+            &format!(
+                "let mut i = match {}_CODEPOINTS.binary_search_by_key(\
+                 &cp, |&(c, _)| c) {{",
+                name
+            ),
+        )?;
+        self.code_line(2, "Ok(i) => i,")?;
+        self.code_line(
+            2,
+            &format!(
+                "Err(_) => return {}_lookup_algorithmic_name(cp, buf),",
+                fname
+            ),
+        )?;
+        self.code_line(1, "};")?;
+        self.code_line(
+            1,
+            &format!("while i > 0 && {}_CODEPOINTS[i - 1].0 == cp {{", name),
+        )?;
+        self.code_line(2, "i -= 1;")?;
+        self.code_line(1, "}")?;
+        self.code_line(1, &format!("let mut data = {}_data(i);", fname))?;
+        self.code_line(1, "let mut len = 0;")?;
+        self.code_line(
+            1,
+            &format!(
+                "while let Some(word) = {}_next_word(&mut data) {{",
+                fname
+            ),
+        )?;
+        self.code_line(2, "if len > 0 {")?;
+        self.code_line(3, "*buf.get_mut(len)? = b' ';")?;
+        self.code_line(3, "len += 1;")?;
+        self.code_line(2, "}")?;
+        self.code_line(2, "let end = len + word.len();")?;
+        self.code_line(
+            2,
+            "buf.get_mut(len..end)?.copy_from_slice(word.as_bytes());",
+        )?;
+        self.code_line(2, "len = end;")?;
+        self.code_line(1, "}")?;
+        self.code_line(1, "::std::str::from_utf8(&buf[..len]).ok()")?;
+        writeln!(self.wtr, "}}")?;
+        self.separator()?;
+
+        writeln!(
+            self.wtr,
+            "/// Return the codepoint with the given name, if one exists."
+        )?;
+        writeln!(self.wtr, "///")?;
+        writeln!(
+            self.wtr,
+            "/// Names that are not in the table are looked up in \
+             `{}_ALGORITHMIC`.",
+            name
+        )?;
+        writeln!(
+            self.wtr,
+            "pub fn {}_lookup_codepoint(name: &str) -> Option<u32> {{",
+            fname
+        )?;
+        self.code_line(
+            1,
+            &format!(
+                "match {}_BY_NAME.binary_search_by(|&i| {}_cmp(i as usize, \
+                 name)) {{",
+                name, fname
+            ),
+        )?;
+        self.code_line(
+            2,
+            &format!(
+                "Ok(i) => Some({}_CODEPOINTS[{}_BY_NAME[i] as usize].0),",
+                name, name
+            ),
+        )?;
+        self.code_line(
+            2,
+            &format!(
+                "Err(_) => {}_lookup_algorithmic_codepoint(name),",
+                fname
+            ),
+        )?;
+        self.code_line(1, "}")?;
+        writeln!(self.wtr, "}}")?;
+        self.separator()?;
+
+        self.names_algorithmic_fns(name, &fname)?;
+        self.names_words_fns(name, &fname)?;
+        Ok(())
+    }
+
+    /// Write the functions that look up algorithmically generated names in
+    /// the `{name}_ALGORITHMIC` table of a compressed names table, along with
+    /// the short names of the Hangul jamo that they use.
+    fn names_algorithmic_fns(
+        &mut self,
+        name: &str,
+        fname: &str,
+    ) -> Result<()> {
+        writeln!(
+            self.wtr,
+            "/// Write the algorithmically generated name of the given \
+             codepoint into `buf`"
+        )?;
+        writeln!(self.wtr, "/// and return it.")?;
+        writeln!(
+            self.wtr,
+            "fn {}_lookup_algorithmic_name(cp: u32, buf: &mut [u8]) \
+             -> Option<&str> {{",
+            fname
+        )?;
+        self.code_line(
+            1,
+            &format!("let &(_, _, prefix) = {}_ALGORITHMIC", name),
+        )?;
+        self.code_line(2, ".iter()")?;
+        self.code_line(
+            2,
+            ".find(|&&(start, end, _)| start <= cp && cp <= end)?;",
+        )?;
+        self.code_line(1, "let mut len = prefix.len();")?;
+        self.code_line(
+            1,
+            "buf.get_mut(..len)?.copy_from_slice(prefix.as_bytes());",
+        )?;
+        self.code_line(1, "if prefix.ends_with('-') {")?;
+        self.code_line(2, "let bits = 32 - cp.leading_zeros() as usize;")?;
+        self.code_line(2, "let digits = ::std::cmp::max(4, (bits + 3) / 4);")?;
+        self.code_line(2, "for k in (0..digits).rev() {")?;
+        self.code_line(3, "let digit = (cp >> (4 * k)) & 0xF;")?;
+        self.code_line(
+            3,
+            "*buf.get_mut(len)? = b\"0123456789ABCDEF\"[digit as usize];",
+        )?;
+        self.code_line(3, "len += 1;")?;
+        self.code_line(2, "}")?;
+        self.code_line(1, "} else {")?;
+        self.code_line(2, "let s = cp.checked_sub(0xAC00)? as usize;")?;
+        self.code_line(2, "let parts = [")?;
+        self.code_line(3, &format!("*{}_JAMO_L.get(s / 588)?,", name))?;
+        self.code_line(3, &format!("{}_JAMO_V[s % 588 / 28],", name))?;
+        self.code_line(3, &format!("{}_JAMO_T[s % 28],", name))?;
+        self.code_line(2, "];")?;
+        self.code_line(2, "for part in &parts {")?;
+        self.code_line(3, "let end = len + part.len();")?;
+        self.code_line(
+            3,
+            "buf.get_mut(len..end)?.copy_from_slice(part.as_bytes());",
+        )?;
+        self.code_line(3, "len = end;")?;
+        self.code_line(2, "}")?;
+        self.code_line(1, "}")?;
+        self.code_line(1, "::std::str::from_utf8(&buf[..len]).ok()")?;
+        writeln!(self.wtr, "}}")?;
+        self.separator()?;
+
+        writeln!(
+            self.wtr,
+            "/// Return the codepoint with the given algorithmically generated \
+             name, if one"
+        )?;
+        writeln!(self.wtr, "/// exists.")?;
+        writeln!(
+            self.wtr,
+            "fn {}_lookup_algorithmic_codepoint(name: &str) -> Option<u32> {{",
+            fname
+        )?;
+        self.code_line(
+            1,
+            &format!("for &(start, end, prefix) in {}_ALGORITHMIC {{", name),
+        )?;
+        self.code_line(2, "if !name.starts_with(prefix) {")?;
+        self.code_line(3, "continue;")?;
+        self.code_line(2, "}")?;
+        self.code_line(2, "let rest = &name[prefix.len()..];")?;
+        self.code_line(2, "let cp = if prefix.ends_with('-') {")?;
+        self.code_line(3, &format!("{}_parse_hex(rest)", fname))?;
+        self.code_line(2, "} else {")?;
+        self.code_line(3, &format!("{}_hangul_codepoint(rest)", fname))?;
+        self.code_line(2, "};")?;
+        self.code_line(2, "match cp {")?;
+        self.code_line(
+            3,
+            "Some(cp) if start <= cp && cp <= end => return Some(cp),",
+        )?;
+        self.code_line(3, "_ => {}")?;
+        self.code_line(2, "}")?;
+        self.code_line(1, "}")?;
+        self.code_line(1, "None")?;
+        writeln!(self.wtr, "}}")?;
+        self.separator()?;
+
+        writeln!(
+            self.wtr,
+            "/// Parse the codepoint at the end of an ideograph name, which is \
+             written in"
+        )?;
+        writeln!(
+            self.wtr,
+            "/// uppercase hexadecimal with at least four digits and no other \
+             leading zeros."
+        )?;
+        writeln!(
+            self.wtr,
+            "fn {}_parse_hex(digits: &str) -> Option<u32> {{",
+            fname
+        )?;
+        self.code_line(1, "let n = digits.len();")?;
+        self.code_line(
+            1,
+            "if n < 4 || n > 8 || (n > 4 && digits.starts_with('0')) {",
+        )?;
+        self.code_line(2, "return None;")?;
+        self.code_line(1, "}")?;
+        self.code_line(1, "let mut cp = 0;")?;
+        self.code_line(1, "for b in digits.bytes() {")?;
+        self.code_line(2, "let digit = if b'0' <= b && b <= b'9' {")?;
+        self.code_line(3, "b - b'0'")?;
+        self.code_line(2, "} else if b'A' <= b && b <= b'F' {")?;
+        self.code_line(3, "b - b'A' + 10")?;
+        self.code_line(2, "} else {")?;
+        self.code_line(3, "return None;")?;
+        self.code_line(2, "};")?;
+        self.code_line(2, "cp = cp * 16 + digit as u32;")?;
+        self.code_line(1, "}")?;
+        self.code_line(1, "Some(cp)")?;
+        writeln!(self.wtr, "}}")?;
+        self.separator()?;
+
+        writeln!(
+            self.wtr,
+            "/// Return the Hangul syllable whose jamo short names make up \
+             `name`."
+        )?;
+        writeln!(
+            self.wtr,
+            "fn {}_hangul_codepoint(name: &str) -> Option<u32> {{",
+            fname
+        )?;
+        self.code_line(
+            1,
+            &format!(
+                "for (l, &lpart) in {}_JAMO_L.iter().enumerate() {{",
+                name
+            ),
+        )?;
+        self.code_line(2, "if !name.starts_with(lpart) {")?;
+        self.code_line(3, "continue;")?;
+        self.code_line(2, "}")?;
+        self.code_line(2, "let rest = &name[lpart.len()..];")?;
+        self.code_line(
+            2,
+            &format!(
+                "for (v, &vpart) in {}_JAMO_V.iter().enumerate() {{",
+                name
+            ),
+        )?;
+        self.code_line(3, "if !rest.starts_with(vpart) {")?;
+        self.code_line(4, "continue;")?;
+        self.code_line(3, "}")?;
+        self.code_line(3, "let rest = &rest[vpart.len()..];")?;
+        self.code_line(
+            3,
+            &format!(
+                "if let Some(t) = {}_JAMO_T.iter().position(|&t| t == rest) {{",
+                name
+            ),
+        )?;
+        self.code_line(
+            4,
+            "return Some(0xAC00 + ((l * 21 + v) * 28 + t) as u32);",
+        )?;
+        self.code_line(3, "}")?;
+        self.code_line(2, "}")?;
+        self.code_line(1, "}")?;
+        self.code_line(1, "None")?;
+        writeln!(self.wtr, "}}")?;
+        self.separator()?;
+
+        let jamo: [(&str, &[&str]); 3] =
+            [("L", HANGUL_JAMO_L), ("V", HANGUL_JAMO_V), ("T", HANGUL_JAMO_T)];
+        writeln!(
+            self.wtr,
+            "// The short names of the leading consonant, vowel and trailing \
+             consonant jamo"
+        )?;
+        writeln!(self.wtr, "// of Hangul syllables, in order.")?;
+        for &(kind, names) in &jamo {
+            writeln!(
+                self.wtr,
+                "const {}_JAMO_{}: [&'static str; {}] = [",
+                name,
+                kind,
+                names.len()
+            )?;
+            self.wtr.indent("  ");
+            for jamo_name in names {
+                self.wtr.write_str(&format!("{:?}, ", jamo_name))?;
+            }
+            writeln!(self.wtr, "];")?;
+        }
+        self.separator()?;
+        Ok(())
+    }
+
+    /// Write the functions that decode the words of the names in a compressed
+    /// names table.
+    fn names_words_fns(&mut self, name: &str, fname: &str) -> Result<()> {
+        writeln!(
+            self.wtr,
+            "/// Compare the name of the entry at index `i` with `name`, \
+             without"
+        )?;
+        writeln!(self.wtr, "/// reconstructing it.")?;
+        writeln!(
+            self.wtr,
+            "fn {}_cmp(i: usize, name: &str) -> ::std::cmp::Ordering {{",
+            fname
+        )?;
+        self.code_line(1, "use std::cmp::Ordering;")?;
+        writeln!(self.wtr)?;
+        self.code_line(1, &format!("let mut data = {}_data(i);", fname))?;
+        self.code_line(1, "let mut rest = name.as_bytes();")?;
+        self.code_line(1, "let mut first = true;")?;
+        self.code_line(
+            1,
+            &format!(
+                "while let Some(word) = {}_next_word(&mut data) {{",
+                fname
+            ),
+        )?;
+        self.code_line(2, "if !first {")?;
+        self.code_line(3, "match rest.split_first() {")?;
+        self.code_line(4, "None => return Ordering::Greater,")?;
+        self.code_line(4, "Some((&b' ', tail)) => rest = tail,")?;
+        self.code_line(4, "Some((&b, _)) => return b' '.cmp(&b),")?;
+        self.code_line(3, "}")?;
+        self.code_line(2, "}")?;
+        self.code_line(2, "first = false;")?;
+        self.code_line(2, "let word = word.as_bytes();")?;
+        self.code_line(2, "let n = ::std::cmp::min(word.len(), rest.len());")?;
+        self.code_line(2, "match word[..n].cmp(&rest[..n]) {")?;
+        self.code_line(
+            3,
+            "Ordering::Equal if word.len() > n => return Ordering::Greater,",
+        )?;
+        self.code_line(3, "Ordering::Equal => rest = &rest[n..],")?;
+        self.code_line(3, "ord => return ord,")?;
+        self.code_line(2, "}")?;
+        self.code_line(1, "}")?;
+        self.code_line(1, "if rest.is_empty() {")?;
+        self.code_line(2, "Ordering::Equal")?;
+        self.code_line(1, "} else {")?;
+        self.code_line(2, "Ordering::Less")?;
+        self.code_line(1, "}")?;
+        writeln!(self.wtr, "}}")?;
+        self.separator()?;
+
+        writeln!(
+            self.wtr,
+            "/// Return the encoded words of the name of the entry at index \
+             `i`."
+        )?;
+        writeln!(self.wtr, "fn {}_data(i: usize) -> &'static [u8] {{", fname)?;
+        self.code_line(
+            1,
+            &format!("let start = {}_CODEPOINTS[i].1 as usize;", name),
+        )?;
+        self.code_line(
+            1,
+            &format!("let end = match {}_CODEPOINTS.get(i + 1) {{", name),
+        )?;
+        self.code_line(2, "Some(&(_, offset)) => offset as usize,")?;
+        self.code_line(2, &format!("None => {}_DATA.len(),", name))?;
+        self.code_line(1, "};")?;
+        self.code_line(1, &format!("&{}_DATA[start..end]", name))?;
+        writeln!(self.wtr, "}}")?;
+        self.separator()?;
+
+        writeln!(
+            self.wtr,
+            "/// Decode the next word from `data` and advance `data` past it."
+        )?;
+        writeln!(
+            self.wtr,
+            "fn {}_next_word(data: &mut &'static [u8]) \
+             -> Option<&'static str> {{",
+            fname
+        )?;
+        self.code_line(1, "let mut index = 0;")?;
+        self.code_line(1, "let mut shift = 0;")?;
+        self.code_line(1, "loop {")?;
+        self.code_line(2, "let (&b, rest) = data.split_first()?;")?;
+        self.code_line(2, "*data = rest;")?;
+        self.code_line(2, "index |= ((b & 0x7F) as usize) << shift;")?;
+        self.code_line(2, "if b & 0x80 == 0 {")?;
+        self.code_line(3, "break;")?;
+        self.code_line(2, "}")?;
+        self.code_line(2, "shift += 7;")?;
+        self.code_line(1, "}")?;
+        self.code_line(
+            1,
+            &format!("let start = {}_WORD_OFFSETS[index] as usize;", name),
+        )?;
+        self.code_line(
+            1,
+            &format!("let end = {}_WORD_OFFSETS[index + 1] as usize;", name),
+        )?;
+        self.code_line(1, &format!("Some(&{}_WORDS[start..end])", name))?;
+        writeln!(self.wtr, "}}")?;
+        Ok(())
+    }
+
+    /// Write a single line of code, indented by four spaces for each level of
+    /// nesting given by `depth`.
+    fn code_line(&mut self, depth: usize, line: &str) -> Result<()> {
+        self.wtr.indent(&"    ".repeat(depth));
+        self.wtr.write_str(line)?;
+        self.wtr.flush_line()?;
+        Ok(())
+    }
+
     /// Write a map that associates strings to `u64` values.
     pub fn string_to_u64(
         &mut self,
@@ -1444,6 +2781,14 @@ pub fn u32_key(cp: u32) -> [u8; 4] {
     key
 }
 
+/// Return the given pair of u32s encoded in big-endian and concatenated.
+fn u32_pair_key(cp1: u32, cp2: u32) -> [u8; 8] {
+    let mut key = [0; 8];
+    BE::write_u32(&mut key[..4], cp1);
+    BE::write_u32(&mut key[4..], cp2);
+    key
+}
+
 /// Convert the given string into a u64, where the least significant byte of
 /// the u64 is the first byte of the string.
 ///
@@ -1464,6 +2809,39 @@ fn pack_str(s: &str) -> Result<u64> {
     Ok(value)
 }
 
+/// Append the given word index to `data` as a variable-length integer.
+///
+/// Each byte holds 7 bits of the index, starting with the least significant
+/// bits. The high bit is set on every byte except the last.
+fn encode_word_index(mut index: usize, data: &mut Vec<u8>) {
+    while index >= 0x80 {
+        data.push((index & 0x7F) as u8 | 0x80);
+        index >>= 7;
+    }
+    data.push(index as u8);
+}
+
+/// The short names of the leading consonant jamo of Hangul syllables, in
+/// order.
+const HANGUL_JAMO_L: &'static [&'static str] = &[
+    "G", "GG", "N", "D", "DD", "R", "M", "B", "BB", "S", "SS", "", "J", "JJ",
+    "C", "K", "T", "P", "H",
+];
+
+/// The short names of the vowel jamo of Hangul syllables, in order.
+const HANGUL_JAMO_V: &'static [&'static str] = &[
+    "A", "AE", "YA", "YAE", "EO", "E", "YEO", "YE", "O", "WA", "WAE", "OE",
+    "YO", "U", "WEO", "WE", "WI", "YU", "EU", "YI", "I",
+];
+
+/// The short names of the trailing consonant jamo of Hangul syllables, in
+/// order. The first is empty, for syllables without a trailing consonant.
+const HANGUL_JAMO_T: &'static [&'static str] = &[
+    "", "G", "GG", "GS", "N", "NJ", "NH", "D", "L", "LG", "LM", "LB", "LS",
+    "LT", "LP", "LH", "M", "B", "BS", "S", "SS", "NG", "J", "C", "K", "T",
+    "P", "H",
+];
+
 /// Return a string representing the smallest unsigned integer type for the
 /// given value.
 fn smallest_unsigned_type(n: u64) -> &'static str {
@@ -1481,9 +2859,12 @@ fn smallest_unsigned_type(n: u64) -> &'static str {
 #[cfg(test)]
 mod tests {
     use super::WriterBuilder;
-    use super::{pack_str, rust_type_name};
+    use super::{encode_word_index, pack_str, rust_type_name};
     use crate::error::Error;
+    use std::env;
+    use std::fs::{self, File};
     use std::io::Cursor;
+    use std::process::Command;
 
     fn unpack_str(mut encoded: u64) -> String {
         let mut value = String::new();
@@ -1506,6 +2887,90 @@ mod tests {
         assert!(pack_str("AB\x00CD").is_err());
     }
 
+    #[test]
+    fn word_index() {
+        let encode = |index| {
+            let mut data = vec![];
+            encode_word_index(index, &mut data);
+            data
+        };
+        assert_eq!(vec![0x00], encode(0));
+        assert_eq!(vec![0x7F], encode(0x7F));
+        assert_eq!(vec![0x80, 0x01], encode(0x80));
+        assert_eq!(vec![0xFF, 0x7F], encode(0x3FFF));
+        assert_eq!(vec![0x80, 0x80, 0x01], encode(0x4000));
+    }
+
+    /// A program that checks lookups in the compressed names table written
+    /// by `names_compressed_round_trip`.
+    const NAMES_COMPRESSED_MAIN: &str = r#"
+include!("names.rs");
+
+fn main() {
+    let names = [
+        (0x0A, "LINE FEED"),
+        (0x0A, "LF"),
+        (0x41, "LATIN CAPITAL LETTER A"),
+        (0x61, "LATIN SMALL LETTER A"),
+        (0x3400, "CJK UNIFIED IDEOGRAPH-3400"),
+        (0x20000, "CJK UNIFIED IDEOGRAPH-20000"),
+        (0xAC01, "HANGUL SYLLABLE GAG"),
+        (0xC544, "HANGUL SYLLABLE A"),
+    ];
+    let mut buf = [0; NAMES_MAX_LEN];
+    for &(cp, name) in &names {
+        assert_eq!(names_lookup_codepoint(name), Some(cp), "{}", name);
+    }
+    for &(cp, name) in &names[2..] {
+        assert_eq!(names_lookup_name(cp, &mut buf), Some(name), "{}", name);
+    }
+    assert_eq!(names_lookup_name(0x0A, &mut buf), Some("LINE FEED"));
+    assert_eq!(names_lookup_name(0x42, &mut buf), None);
+    assert_eq!(names_lookup_name(0x41, &mut [0; 4]), None);
+    assert_eq!(names_lookup_codepoint("LATIN CAPITAL LETTER"), None);
+    assert_eq!(names_lookup_codepoint("CJK UNIFIED IDEOGRAPH-03400"), None);
+    assert_eq!(names_lookup_codepoint("CJK UNIFIED IDEOGRAPH-340a"), None);
+    assert_eq!(names_lookup_codepoint("CJK UNIFIED IDEOGRAPH-4E00"), None);
+    assert_eq!(names_lookup_codepoint("HANGUL SYLLABLE GAGX"), None);
+}
+"#;
+
+    #[test]
+    fn names_compressed_round_trip() {
+        let dir = env::temp_dir()
+            .join(format!("ucd-generate-names-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let names = vec![
+            (0x41, "LATIN CAPITAL LETTER A".to_string()),
+            (0x0A, "LINE FEED".to_string()),
+            (0x0A, "LF".to_string()),
+            (0x61, "LATIN SMALL LETTER A".to_string()),
+        ];
+        let algorithmic = vec![
+            (0x3400, 0x4DBF, "CJK UNIFIED IDEOGRAPH-".to_string()),
+            (0xAC00, 0xD7A3, "HANGUL SYLLABLE ".to_string()),
+            (0x20000, 0x2A6DF, "CJK UNIFIED IDEOGRAPH-".to_string()),
+        ];
+        let file = File::create(dir.join("names.rs")).unwrap();
+        let mut writer = WriterBuilder::new("test").from_writer(file);
+        writer.names_compressed("names", &names, &algorithmic).unwrap();
+        fs::write(dir.join("main.rs"), NAMES_COMPRESSED_MAIN).unwrap();
+
+        let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+        let exe = dir.join("names");
+        let status = Command::new(rustc)
+            .arg("--edition=2018")
+            .arg("-o")
+            .arg(&exe)
+            .arg(dir.join("main.rs"))
+            .status()
+            .unwrap();
+        assert!(status.success());
+        assert!(Command::new(&exe).status().unwrap().success());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
     #[test]
     fn test_rust_type_name() {
         assert_eq!(&rust_type_name("simple"), "Simple");
@@ -0,0 +1,145 @@
+use std::path::Path;
+use std::str::FromStr;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::common::{Codepoint, CodepointIter, UcdFile, UcdFileByCodepoint};
+use crate::error::Error;
+
+/// Represents a single row in the `BidiBrackets.txt` file.
+///
+/// The field names were taken from the header of BidiBrackets.txt.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct BidiBracket {
+    /// The codepoint corresponding to this row.
+    pub codepoint: Codepoint,
+    /// The codepoint of the bracket that pairs with `codepoint`.
+    pub bidi_paired_bracket: Codepoint,
+    /// Whether `codepoint` is an opening or a closing bracket.
+    pub bidi_paired_bracket_type: BidiPairedBracketType,
+}
+
+impl UcdFile for BidiBracket {
+    fn relative_file_path() -> &'static Path {
+        Path::new("BidiBrackets.txt")
+    }
+}
+
+impl UcdFileByCodepoint for BidiBracket {
+    fn codepoints(&self) -> CodepointIter {
+        self.codepoint.into_iter()
+    }
+}
+
+impl FromStr for BidiBracket {
+    type Err = Error;
+
+    fn from_str(line: &str) -> Result<BidiBracket, Error> {
+        static PARTS: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(
+                r"(?x)
+                ^
+                \s*(?P<codepoint>[A-F0-9]+)\s*;
+                \s*(?P<paired>[A-F0-9]+)\s*;
+                \s*(?P<kind>[a-z]+)\s*
+                (?:\#.*)?
+                $
+                ",
+            )
+            .unwrap()
+        });
+        let caps = match PARTS.captures(line.trim()) {
+            Some(caps) => caps,
+            None => return err!("invalid BidiBrackets line: '{}'", line),
+        };
+
+        Ok(BidiBracket {
+            codepoint: caps["codepoint"].parse()?,
+            bidi_paired_bracket: caps["paired"].parse()?,
+            bidi_paired_bracket_type: caps["kind"].parse()?,
+        })
+    }
+}
+
+/// The value of the Bidi_Paired_Bracket_Type property.
+///
+/// Codepoints that are not listed in `BidiBrackets.txt` have the value
+/// `None`, which is never used in the file itself and so is not represented
+/// here.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum BidiPairedBracketType {
+    /// An opening paired bracket (`o`).
+    #[default]
+    Open,
+    /// A closing paired bracket (`c`).
+    Close,
+}
+
+impl BidiPairedBracketType {
+    /// Returns the long name of this bracket type, as used in
+    /// `PropertyValueAliases.txt`.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            BidiPairedBracketType::Open => "Open",
+            BidiPairedBracketType::Close => "Close",
+        }
+    }
+}
+
+impl FromStr for BidiPairedBracketType {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<BidiPairedBracketType, Error> {
+        match s {
+            "o" => Ok(BidiPairedBracketType::Open),
+            "c" => Ok(BidiPairedBracketType::Close),
+            _ => err!("unrecognized Bidi_Paired_Bracket_Type: '{}'", s),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::common::Codepoint;
+
+    use super::{BidiBracket, BidiPairedBracketType};
+
+    fn codepoint(n: u32) -> Codepoint {
+        Codepoint::from_u32(n).unwrap()
+    }
+
+    #[test]
+    fn parse_open() {
+        let line = "0028; 0029; o # LEFT PARENTHESIS\n";
+        let data: BidiBracket = line.parse().unwrap();
+        assert_eq!(
+            data,
+            BidiBracket {
+                codepoint: codepoint(0x0028),
+                bidi_paired_bracket: codepoint(0x0029),
+                bidi_paired_bracket_type: BidiPairedBracketType::Open,
+            }
+        );
+    }
+
+    #[test]
+    fn parse_close() {
+        let line = "232A; 2329; c # RIGHT-POINTING ANGLE BRACKET\n";
+        let data: BidiBracket = line.parse().unwrap();
+        assert_eq!(
+            data,
+            BidiBracket {
+                codepoint: codepoint(0x232A),
+                bidi_paired_bracket: codepoint(0x2329),
+                bidi_paired_bracket_type: BidiPairedBracketType::Close,
+            }
+        );
+    }
+
+    #[test]
+    fn parse_invalid_type() {
+        let line = "0028; 0029; x # LEFT PARENTHESIS\n";
+        assert!(line.parse::<BidiBracket>().is_err());
+    }
+}
@@ -0,0 +1,63 @@
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::common::{
+    parse_codepoint_association, CodepointIter, Codepoints, UcdFile,
+    UcdFileByCodepoint,
+};
+use crate::error::Error;
+
+/// A single row in the `Blocks.txt` file.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Block {
+    /// The codepoint range for this entry.
+    pub codepoints: Codepoints,
+    /// The name of the block assigned to the codepoints in this entry.
+    ///
+    /// Note that this is the name as written in `Blocks.txt`, which may
+    /// differ from the name used in `PropertyValueAliases.txt` (e.g.,
+    /// `Basic Latin` versus `Basic_Latin`).
+    pub name: String,
+}
+
+impl UcdFile for Block {
+    fn relative_file_path() -> &'static Path {
+        Path::new("Blocks.txt")
+    }
+}
+
+impl UcdFileByCodepoint for Block {
+    fn codepoints(&self) -> CodepointIter {
+        self.codepoints.into_iter()
+    }
+}
+
+impl FromStr for Block {
+    type Err = Error;
+
+    fn from_str(line: &str) -> Result<Block, Error> {
+        let (codepoints, name) = parse_codepoint_association(line)?;
+        Ok(Block { codepoints, name: name.to_string() })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Block;
+
+    #[test]
+    fn parse_range() {
+        let line = "0000..007F; Basic Latin\n";
+        let row: Block = line.parse().unwrap();
+        assert_eq!(row.codepoints, (0x0000, 0x007F));
+        assert_eq!(row.name, "Basic Latin");
+    }
+
+    #[test]
+    fn parse_name_with_punctuation() {
+        let line = "0080..00FF; Latin-1 Supplement\n";
+        let row: Block = line.parse().unwrap();
+        assert_eq!(row.codepoints, (0x0080, 0x00FF));
+        assert_eq!(row.name, "Latin-1 Supplement");
+    }
+}
@@ -0,0 +1,142 @@
+use std::path::Path;
+use std::str::FromStr;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::common::{Codepoint, UcdFile};
+use crate::error::Error;
+
+/// A single row in the `CJKRadicals.txt` file.
+///
+/// Each row associates a radical number from the KangXi radical-stroke
+/// system with the CJK radical character and the CJK unified ideograph that
+/// represent it.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CJKRadical {
+    /// The radical number, from 1 to 214.
+    pub radical: u8,
+    /// The number of apostrophes following the radical number. A value of `1`
+    /// indicates a Chinese simplified form of the radical. Later versions of
+    /// the UCD use `2` for non-Chinese simplified forms.
+    pub simplified: u8,
+    /// The CJK radical character for this radical, if there is one.
+    pub radical_codepoint: Option<Codepoint>,
+    /// The CJK unified ideograph corresponding to this radical.
+    pub ideograph: Codepoint,
+}
+
+impl CJKRadical {
+    /// Returns the radical number of this row as it appears in the file,
+    /// including any apostrophes, e.g., `90'`.
+    pub fn radical_number(&self) -> String {
+        format_radical_number(self.radical, self.simplified)
+    }
+}
+
+impl UcdFile for CJKRadical {
+    fn relative_file_path() -> &'static Path {
+        Path::new("CJKRadicals.txt")
+    }
+}
+
+impl FromStr for CJKRadical {
+    type Err = Error;
+
+    fn from_str(line: &str) -> Result<CJKRadical, Error> {
+        static PARTS: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(
+                r"(?x)
+                ^
+                \s*(?P<radical>[0-9]+'*)\s*;
+                \s*(?P<radical_codepoint>[A-F0-9]*)\s*;
+                \s*(?P<ideograph>[A-F0-9]+)\s*
+                (?:\x23.*)?
+                $
+                ",
+            )
+            .unwrap()
+        });
+
+        let caps = match PARTS.captures(line.trim()) {
+            Some(caps) => caps,
+            None => return err!("invalid CJKRadicals line: '{}'", line),
+        };
+        let (radical, simplified) = parse_radical_number(&caps["radical"])?;
+        let radical_codepoint = match &caps["radical_codepoint"] {
+            "" => None,
+            cp => Some(cp.parse()?),
+        };
+        Ok(CJKRadical {
+            radical,
+            simplified,
+            radical_codepoint,
+            ideograph: caps["ideograph"].parse()?,
+        })
+    }
+}
+
+/// Parses a radical number, which may be followed by one or more
+/// apostrophes, into the number and the count of apostrophes.
+pub(crate) fn parse_radical_number(s: &str) -> Result<(u8, u8), Error> {
+    let number = s.trim_end_matches('\'');
+    let simplified = (s.len() - number.len()) as u8;
+    match number.parse() {
+        Ok(radical) if (1..=214).contains(&radical) => {
+            Ok((radical, simplified))
+        }
+        _ => err!("invalid radical number: '{}'", s),
+    }
+}
+
+/// Formats a radical number with the given number of apostrophes.
+pub(crate) fn format_radical_number(radical: u8, simplified: u8) -> String {
+    let mut s = radical.to_string();
+    for _ in 0..simplified {
+        s.push('\'');
+    }
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CJKRadical;
+
+    #[test]
+    fn parse() {
+        let line = "1; 2F00; 4E00\n";
+        let row: CJKRadical = line.parse().unwrap();
+        assert_eq!(row.radical, 1);
+        assert_eq!(row.simplified, 0);
+        assert_eq!(row.radical_codepoint.unwrap(), 0x2F00);
+        assert_eq!(row.ideograph, 0x4E00);
+        assert_eq!(row.radical_number(), "1");
+    }
+
+    #[test]
+    fn parse_simplified() {
+        let line = "90'; 2EA6; 4E2C\n";
+        let row: CJKRadical = line.parse().unwrap();
+        assert_eq!(row.radical, 90);
+        assert_eq!(row.simplified, 1);
+        assert_eq!(row.radical_codepoint.unwrap(), 0x2EA6);
+        assert_eq!(row.ideograph, 0x4E2C);
+        assert_eq!(row.radical_number(), "90'");
+    }
+
+    #[test]
+    fn parse_no_radical_codepoint() {
+        let line = "182''; ; 322C5\n";
+        let row: CJKRadical = line.parse().unwrap();
+        assert_eq!(row.radical, 182);
+        assert_eq!(row.simplified, 2);
+        assert_eq!(row.radical_codepoint, None);
+        assert_eq!(row.ideograph, 0x322C5);
+    }
+
+    #[test]
+    fn parse_invalid_radical() {
+        let line = "215; 2F00; 4E00\n";
+        assert!(line.parse::<CJKRadical>().is_err());
+    }
+}
@@ -73,6 +73,79 @@ where
     Ok(map)
 }
 
+/// Parse the `@missing` lines of a particular file in the UCD.
+///
+/// Many UCD files only list codepoints whose property value differs from a
+/// default, and specify that default in a comment of the form
+/// `# @missing: 0000..10FFFF; <value>`. Since comments are otherwise skipped
+/// when parsing records, this function can be used to retrieve the defaults.
+///
+/// Each default is returned as the codepoints it applies to along with the
+/// remainder of the line (trimmed, and possibly containing more than one
+/// field). Defaults are returned in the order in which they appear in the
+/// file, where later defaults take precedence over earlier ones.
+///
+/// The given directory should be the directory to the UCD.
+pub fn parse_missing_defaults<P, D>(
+    ucd_dir: P,
+) -> Result<Vec<(Codepoints, String)>, Error>
+where
+    P: AsRef<Path>,
+    D: UcdFile,
+{
+    let path = D::file_path(ucd_dir);
+    let file = File::open(&path).map_err(|e| Error {
+        kind: ErrorKind::Io(e),
+        line: None,
+        path: Some(path.clone()),
+    })?;
+    let mut defaults = vec![];
+    for (i, line) in io::BufReader::new(file).lines().enumerate() {
+        let line = line.map_err(|e| Error {
+            kind: ErrorKind::Io(e),
+            line: None,
+            path: Some(path.clone()),
+        })?;
+        let missing = parse_missing_line(&line).map_err(|mut err| {
+            err.line = Some(i as u64 + 1);
+            err.path = Some(path.clone());
+            err
+        })?;
+        if let Some(missing) = missing {
+            defaults.push(missing);
+        }
+    }
+    Ok(defaults)
+}
+
+/// Parse a single `@missing` line. If the given line is not an `@missing`
+/// line, then `None` is returned.
+fn parse_missing_line(
+    line: &str,
+) -> Result<Option<(Codepoints, String)>, Error> {
+    static PARTS: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(
+            r"(?x)
+            ^
+            \x23\s*@missing:
+            \s*(?P<codepoints>[^\s;]+)\s*;
+            \s*(?P<value>.*?)\s*
+            $
+            ",
+        )
+        .unwrap()
+    });
+
+    if !line.trim_start().starts_with('#') || !line.contains("@missing") {
+        return Ok(None);
+    }
+    let caps = match PARTS.captures(line.trim()) {
+        Some(caps) => caps,
+        None => return err!("invalid @missing line: '{}'", line),
+    };
+    Ok(Some((caps["codepoints"].parse()?, caps["value"].to_string())))
+}
+
 /// Given a path pointing at the root of the `ucd_dir`, attempts to determine
 /// it's unicode version.
 ///
@@ -134,6 +207,21 @@ fn first_line(path: &Path) -> Result<String, Error> {
     Ok(line_contents)
 }
 
+/// Returns the path to the given emoji file, falling back to the location
+/// used by UCDs older than 13.0.0 if that is the only one that exists.
+pub fn emoji_file_path(ucd_dir: &Path, relative: &Path) -> PathBuf {
+    let std = ucd_dir.join(relative);
+    if std.exists() {
+        return std;
+    }
+    let legacy = ucd_dir.join(relative.file_name().unwrap());
+    if legacy.exists() {
+        legacy
+    } else {
+        std
+    }
+}
+
 /// A helper function for parsing a common record format that associates one
 /// or more codepoints with a string value.
 pub fn parse_codepoint_association<'a>(
@@ -596,3 +684,32 @@ impl Iterator for CodepointIter {
         Some(Codepoint::from_u32(current).unwrap())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::parse_missing_line;
+
+    #[test]
+    fn parse_missing() {
+        let line = "# @missing: 0000..10FFFF; R\n";
+        let (cps, value) = parse_missing_line(line).unwrap().unwrap();
+        assert_eq!(cps, (0x0000, 0x10FFFF));
+        assert_eq!(value, "R");
+
+        let line = "# @missing: 0000..10FFFF; NFC_QC; Yes\n";
+        let (cps, value) = parse_missing_line(line).unwrap().unwrap();
+        assert_eq!(cps, (0x0000, 0x10FFFF));
+        assert_eq!(value, "NFC_QC; Yes");
+    }
+
+    #[test]
+    fn parse_not_missing() {
+        assert!(parse_missing_line("# Some comment\n").unwrap().is_none());
+        assert!(parse_missing_line("0000..001F; R\n").unwrap().is_none());
+    }
+
+    #[test]
+    fn parse_invalid_missing() {
+        assert!(parse_missing_line("# @missing: R\n").is_err());
+    }
+}
@@ -0,0 +1,83 @@
+use std::path::Path;
+use std::str::FromStr;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::common::{Codepoint, CodepointIter, UcdFile, UcdFileByCodepoint};
+use crate::error::Error;
+
+/// A single row in the `CompositionExclusions.txt` file.
+///
+/// Each row names a single codepoint that is excluded from canonical
+/// composition, even though it has a canonical decomposition mapping.
+///
+/// Note that this file does not list singletons or non-starter
+/// decompositions (those only appear in comments). The complete set of
+/// exclusions is available as the `Full_Composition_Exclusion` property.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CompositionExclusion {
+    /// The codepoint that is excluded from composition.
+    pub codepoint: Codepoint,
+}
+
+impl UcdFile for CompositionExclusion {
+    fn relative_file_path() -> &'static Path {
+        Path::new("CompositionExclusions.txt")
+    }
+}
+
+impl UcdFileByCodepoint for CompositionExclusion {
+    fn codepoints(&self) -> CodepointIter {
+        self.codepoint.into_iter()
+    }
+}
+
+impl FromStr for CompositionExclusion {
+    type Err = Error;
+
+    fn from_str(line: &str) -> Result<CompositionExclusion, Error> {
+        static PARTS: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(
+                r"(?x)
+                ^
+                \s*(?P<codepoint>[A-F0-9]+)\s*
+                (?:\#.*)?
+                $
+                ",
+            )
+            .unwrap()
+        });
+        let caps = match PARTS.captures(line.trim()) {
+            Some(caps) => caps,
+            None => {
+                return err!("invalid CompositionExclusions line: '{}'", line)
+            }
+        };
+        Ok(CompositionExclusion { codepoint: caps["codepoint"].parse()? })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CompositionExclusion;
+
+    #[test]
+    fn parse_single() {
+        let line = "0958    #  DEVANAGARI LETTER QA\n";
+        let row: CompositionExclusion = line.parse().unwrap();
+        assert_eq!(row.codepoint, 0x0958);
+    }
+
+    #[test]
+    fn parse_no_comment() {
+        let line = "1D15E\n";
+        let row: CompositionExclusion = line.parse().unwrap();
+        assert_eq!(row.codepoint, 0x1D15E);
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert!("0958..095F # ".parse::<CompositionExclusion>().is_err());
+    }
+}
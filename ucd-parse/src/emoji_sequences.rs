@@ -0,0 +1,207 @@
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::common::{
+    emoji_file_path, parse_codepoint_sequence, Codepoint, CodepointRange,
+    UcdFile,
+};
+use crate::error::Error;
+
+/// A single row in the `emoji-sequences.txt` file.
+///
+/// Each row associates either a sequence of codepoints or a range of single
+/// codepoints with a property of strings, such as `Basic_Emoji` or
+/// `RGI_Emoji_Flag_Sequence`.
+///
+/// Note that `emoji-sequences.txt` is not formally part of the Unicode
+/// Character Database. You can download the Emoji data files separately here:
+/// https://unicode.org/Public/emoji/
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct EmojiSequence {
+    /// The sequence or sequences of codepoints in this entry.
+    pub codepoints: EmojiSequenceCodepoints,
+    /// The property of strings that contains the sequences in this entry.
+    pub property: String,
+    /// The description of this entry, e.g., `flag: Canada`.
+    pub description: String,
+}
+
+impl UcdFile for EmojiSequence {
+    fn relative_file_path() -> &'static Path {
+        Path::new("emoji/emoji-sequences.txt")
+    }
+
+    fn file_path<P: AsRef<Path>>(ucd_dir: P) -> PathBuf {
+        emoji_file_path(ucd_dir.as_ref(), Self::relative_file_path())
+    }
+}
+
+impl FromStr for EmojiSequence {
+    type Err = Error;
+
+    fn from_str(line: &str) -> Result<EmojiSequence, Error> {
+        let (codepoints, property, description) = parse_emoji_sequence(line)?;
+        Ok(EmojiSequence { codepoints, property, description })
+    }
+}
+
+/// A single row in the `emoji-zwj-sequences.txt` file.
+///
+/// Every row in this file associates a sequence of codepoints joined by
+/// U+200D ZERO WIDTH JOINER with the `RGI_Emoji_ZWJ_Sequence` property.
+///
+/// Note that `emoji-zwj-sequences.txt` is not formally part of the Unicode
+/// Character Database. You can download the Emoji data files separately here:
+/// https://unicode.org/Public/emoji/
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct EmojiZwjSequence {
+    /// The sequence of codepoints in this entry.
+    pub codepoints: EmojiSequenceCodepoints,
+    /// The property of strings that contains the sequence in this entry.
+    pub property: String,
+    /// The description of this entry, e.g., `family: man, woman, boy`.
+    pub description: String,
+}
+
+impl UcdFile for EmojiZwjSequence {
+    fn relative_file_path() -> &'static Path {
+        Path::new("emoji/emoji-zwj-sequences.txt")
+    }
+
+    fn file_path<P: AsRef<Path>>(ucd_dir: P) -> PathBuf {
+        emoji_file_path(ucd_dir.as_ref(), Self::relative_file_path())
+    }
+}
+
+impl FromStr for EmojiZwjSequence {
+    type Err = Error;
+
+    fn from_str(line: &str) -> Result<EmojiZwjSequence, Error> {
+        let (codepoints, property, description) = parse_emoji_sequence(line)?;
+        Ok(EmojiZwjSequence { codepoints, property, description })
+    }
+}
+
+/// The codepoints in a single row of an emoji sequence file.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum EmojiSequenceCodepoints {
+    /// A range of codepoints, where each codepoint in the range is a
+    /// sequence of length one. Ranges are only used by `Basic_Emoji`.
+    Range(CodepointRange),
+    /// A single (non-empty) sequence of codepoints.
+    Sequence(Vec<Codepoint>),
+}
+
+impl EmojiSequenceCodepoints {
+    /// Returns every sequence of codepoints in this entry as a string.
+    ///
+    /// If a sequence contains a surrogate codepoint, then it is omitted.
+    pub fn strings(&self) -> Vec<String> {
+        match *self {
+            EmojiSequenceCodepoints::Range(range) => range
+                .into_iter()
+                .filter_map(|cp| cp.scalar().map(|c| c.to_string()))
+                .collect(),
+            EmojiSequenceCodepoints::Sequence(ref cps) => cps
+                .iter()
+                .map(|cp| cp.scalar())
+                .collect::<Option<String>>()
+                .into_iter()
+                .collect(),
+        }
+    }
+}
+
+impl Default for EmojiSequenceCodepoints {
+    fn default() -> EmojiSequenceCodepoints {
+        EmojiSequenceCodepoints::Sequence(vec![])
+    }
+}
+
+/// Parses a single row of `emoji-sequences.txt` or
+/// `emoji-zwj-sequences.txt`, which consists of a codepoint range or
+/// sequence, a property name and a description.
+fn parse_emoji_sequence(
+    line: &str,
+) -> Result<(EmojiSequenceCodepoints, String, String), Error> {
+    static PARTS: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(
+            r"(?x)
+            ^
+            \s*(?P<codepoints>[^;]+?)\s*;
+            \s*(?P<property>[^\s;]+)\s*;
+            \s*(?P<description>[^\x23]*?)\s*
+            (?:\x23.*)?
+            $
+            ",
+        )
+        .unwrap()
+    });
+
+    let caps = match PARTS.captures(line.trim()) {
+        Some(caps) => caps,
+        None => return err!("invalid emoji sequence line: '{}'", line),
+    };
+    let codepoints = if caps["codepoints"].contains("..") {
+        EmojiSequenceCodepoints::Range(caps["codepoints"].parse()?)
+    } else {
+        let cps = parse_codepoint_sequence(&caps["codepoints"])?;
+        if cps.is_empty() {
+            return err!("empty emoji sequence in line: '{}'", line);
+        }
+        EmojiSequenceCodepoints::Sequence(cps)
+    };
+    Ok((
+        codepoints,
+        caps["property"].to_string(),
+        caps["description"].to_string(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EmojiSequence, EmojiSequenceCodepoints, EmojiZwjSequence};
+
+    #[test]
+    fn parse_range() {
+        let line = "231A..231B    ; Basic_Emoji                  ; watch                                                          # E0.6   [2] (⌚..⌛)\n";
+        let row: EmojiSequence = line.parse().unwrap();
+        assert_eq!(
+            row.codepoints,
+            EmojiSequenceCodepoints::Range("231A..231B".parse().unwrap())
+        );
+        assert_eq!(row.property, "Basic_Emoji");
+        assert_eq!(row.description, "watch");
+        assert_eq!(row.codepoints.strings(), vec!["\u{231A}", "\u{231B}"]);
+    }
+
+    #[test]
+    fn parse_sequence() {
+        let line = "0023 FE0F 20E3; Emoji_Keycap_Sequence        ; keycap: \\x{23}                                                 # E0.6   [1] (#️⃣)\n";
+        let row: EmojiSequence = line.parse().unwrap();
+        assert_eq!(row.property, "Emoji_Keycap_Sequence");
+        assert_eq!(row.description, "keycap: \\x{23}");
+        assert_eq!(row.codepoints.strings(), vec!["#\u{FE0F}\u{20E3}"]);
+    }
+
+    #[test]
+    fn parse_zwj_sequence() {
+        let line = "1F468 200D 2764 FE0F 200D 1F468             ; RGI_Emoji_ZWJ_Sequence  ; couple with heart: man, man                                    # E2.0   [1] (👨‍❤️‍👨)\n";
+        let row: EmojiZwjSequence = line.parse().unwrap();
+        assert_eq!(row.property, "RGI_Emoji_ZWJ_Sequence");
+        assert_eq!(row.description, "couple with heart: man, man");
+        assert_eq!(
+            row.codepoints.strings(),
+            vec!["\u{1F468}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}"]
+        );
+    }
+
+    #[test]
+    fn parse_invalid() {
+        let line = "1F468 200D 1F468 # missing fields\n";
+        assert!(line.parse::<EmojiZwjSequence>().is_err());
+    }
+}
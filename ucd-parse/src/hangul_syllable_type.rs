@@ -0,0 +1,66 @@
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::common::{
+    parse_codepoint_association, CodepointIter, Codepoints, UcdFile,
+    UcdFileByCodepoint,
+};
+use crate::error::Error;
+
+/// A single row in the `HangulSyllableType.txt` file.
+///
+/// This file gives the values of the Hangul_Syllable_Type property. Codepoints
+/// that are not listed have the value `NA` (`Not_Applicable`).
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct HangulSyllableType {
+    /// The codepoint or codepoint range for this entry.
+    pub codepoints: Codepoints,
+    /// The Hangul_Syllable_Type of the codepoints in this entry, as an
+    /// abbreviation (e.g., `L` or `LVT`).
+    pub syllable_type: String,
+}
+
+impl UcdFile for HangulSyllableType {
+    fn relative_file_path() -> &'static Path {
+        Path::new("HangulSyllableType.txt")
+    }
+}
+
+impl UcdFileByCodepoint for HangulSyllableType {
+    fn codepoints(&self) -> CodepointIter {
+        self.codepoints.into_iter()
+    }
+}
+
+impl FromStr for HangulSyllableType {
+    type Err = Error;
+
+    fn from_str(line: &str) -> Result<HangulSyllableType, Error> {
+        let (codepoints, syllable_type) = parse_codepoint_association(line)?;
+        Ok(HangulSyllableType {
+            codepoints,
+            syllable_type: syllable_type.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HangulSyllableType;
+
+    #[test]
+    fn parse_single() {
+        let line = "AC00          ; LV # Lo       HANGUL SYLLABLE GA\n";
+        let row: HangulSyllableType = line.parse().unwrap();
+        assert_eq!(row.codepoints, 0xAC00);
+        assert_eq!(row.syllable_type, "LV");
+    }
+
+    #[test]
+    fn parse_range() {
+        let line = "AC01..AC1B    ; LVT # Lo  [27] HANGUL SYLLABLE GAG..HANGUL SYLLABLE GAH\n";
+        let row: HangulSyllableType = line.parse().unwrap();
+        assert_eq!(row.codepoints, (0xAC01, 0xAC1B));
+        assert_eq!(row.syllable_type, "LVT");
+    }
+}
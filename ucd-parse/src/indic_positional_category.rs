@@ -0,0 +1,65 @@
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::common::{
+    parse_codepoint_association, CodepointIter, Codepoints, UcdFile,
+    UcdFileByCodepoint,
+};
+use crate::error::Error;
+
+/// A single row in the `IndicPositionalCategory.txt` file.
+///
+/// This file gives the values of the Indic_Positional_Category property.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct IndicPositionalCategory {
+    /// The codepoint or codepoint range for this entry.
+    pub codepoints: Codepoints,
+    /// The Indic_Positional_Category of the codepoints in this entry.
+    pub category: String,
+}
+
+impl UcdFile for IndicPositionalCategory {
+    fn relative_file_path() -> &'static Path {
+        Path::new("IndicPositionalCategory.txt")
+    }
+}
+
+impl UcdFileByCodepoint for IndicPositionalCategory {
+    fn codepoints(&self) -> CodepointIter {
+        self.codepoints.into_iter()
+    }
+}
+
+impl FromStr for IndicPositionalCategory {
+    type Err = Error;
+
+    fn from_str(line: &str) -> Result<IndicPositionalCategory, Error> {
+        let (codepoints, category) = parse_codepoint_association(line)?;
+        Ok(IndicPositionalCategory {
+            codepoints,
+            category: category.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IndicPositionalCategory;
+
+    #[test]
+    fn parse_single() {
+        let line =
+            "093E          ; Right # Mc       DEVANAGARI VOWEL SIGN AA\n";
+        let row: IndicPositionalCategory = line.parse().unwrap();
+        assert_eq!(row.codepoints, 0x093E);
+        assert_eq!(row.category, "Right");
+    }
+
+    #[test]
+    fn parse_range() {
+        let line = "0941..0944    ; Bottom # Mn   [4] DEVANAGARI VOWEL SIGN U..DEVANAGARI VOWEL SIGN VOCALIC RR\n";
+        let row: IndicPositionalCategory = line.parse().unwrap();
+        assert_eq!(row.codepoints, (0x0941, 0x0944));
+        assert_eq!(row.category, "Bottom");
+    }
+}
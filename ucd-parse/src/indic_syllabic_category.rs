@@ -0,0 +1,65 @@
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::common::{
+    parse_codepoint_association, CodepointIter, Codepoints, UcdFile,
+    UcdFileByCodepoint,
+};
+use crate::error::Error;
+
+/// A single row in the `IndicSyllabicCategory.txt` file.
+///
+/// This file gives the values of the Indic_Syllabic_Category property.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct IndicSyllabicCategory {
+    /// The codepoint or codepoint range for this entry.
+    pub codepoints: Codepoints,
+    /// The Indic_Syllabic_Category of the codepoints in this entry.
+    pub category: String,
+}
+
+impl UcdFile for IndicSyllabicCategory {
+    fn relative_file_path() -> &'static Path {
+        Path::new("IndicSyllabicCategory.txt")
+    }
+}
+
+impl UcdFileByCodepoint for IndicSyllabicCategory {
+    fn codepoints(&self) -> CodepointIter {
+        self.codepoints.into_iter()
+    }
+}
+
+impl FromStr for IndicSyllabicCategory {
+    type Err = Error;
+
+    fn from_str(line: &str) -> Result<IndicSyllabicCategory, Error> {
+        let (codepoints, category) = parse_codepoint_association(line)?;
+        Ok(IndicSyllabicCategory {
+            codepoints,
+            category: category.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IndicSyllabicCategory;
+
+    #[test]
+    fn parse_single() {
+        let line =
+            "0903          ; Visarga # Mc       DEVANAGARI SIGN VISARGA\n";
+        let row: IndicSyllabicCategory = line.parse().unwrap();
+        assert_eq!(row.codepoints, 0x0903);
+        assert_eq!(row.category, "Visarga");
+    }
+
+    #[test]
+    fn parse_range() {
+        let line = "0905..0914    ; Vowel_Independent # Lo  [16] DEVANAGARI LETTER A..DEVANAGARI LETTER AU\n";
+        let row: IndicSyllabicCategory = line.parse().unwrap();
+        assert_eq!(row.codepoints, (0x0905, 0x0914));
+        assert_eq!(row.category, "Vowel_Independent");
+    }
+}
@@ -5,36 +5,63 @@ A library for parsing the Unicode character database.
 #![deny(missing_docs)]
 
 pub use crate::common::{
-    parse, parse_by_codepoint, parse_many_by_codepoint, ucd_directory_version,
-    Codepoint, CodepointIter, CodepointRange, Codepoints, UcdFile,
-    UcdFileByCodepoint, UcdLineParser,
+    parse, parse_by_codepoint, parse_many_by_codepoint,
+    parse_missing_defaults, ucd_directory_version, Codepoint, CodepointIter,
+    CodepointRange, Codepoints, UcdFile, UcdFileByCodepoint, UcdLineParser,
 };
 pub use crate::error::{Error, ErrorKind};
 
 pub use crate::age::Age;
 pub use crate::arabic_shaping::ArabicShaping;
+pub use crate::bidi_brackets::{BidiBracket, BidiPairedBracketType};
 pub use crate::bidi_mirroring_glyph::BidiMirroring;
+pub use crate::blocks::Block;
 pub use crate::case_folding::{CaseFold, CaseStatus};
+pub use crate::cjk_radicals::CJKRadical;
+pub use crate::composition_exclusions::CompositionExclusion;
 pub use crate::core_properties::CoreProperty;
 pub use crate::east_asian_width::EastAsianWidth;
 pub use crate::emoji_properties::EmojiProperty;
+pub use crate::emoji_sequences::{
+    EmojiSequence, EmojiSequenceCodepoints, EmojiZwjSequence,
+};
 pub use crate::grapheme_cluster_break::{
     GraphemeClusterBreak, GraphemeClusterBreakTest,
 };
+pub use crate::hangul_syllable_type::HangulSyllableType;
+pub use crate::indic_positional_category::IndicPositionalCategory;
+pub use crate::indic_syllabic_category::IndicSyllabicCategory;
 pub use crate::jamo_short_name::JamoShortName;
-pub use crate::line_break::LineBreakTest;
+pub use crate::line_break::{LineBreak, LineBreakTest};
 pub use crate::name_aliases::{NameAlias, NameAliasLabel};
+pub use crate::named_sequences::{NamedSequence, ProvisionalNamedSequence};
+pub use crate::names_list::{
+    parse_names_list, NamesListAnnotation, NamesListBlock, NamesListEntry,
+    NamesListSubhead,
+};
+pub use crate::normalization_properties::{
+    NormalizationProperty, NormalizationPropertyValue,
+};
 pub use crate::prop_list::Property;
 pub use crate::property_aliases::PropertyAlias;
 pub use crate::property_value_aliases::PropertyValueAlias;
 pub use crate::script_extensions::ScriptExtension;
 pub use crate::scripts::Script;
 pub use crate::sentence_break::{SentenceBreak, SentenceBreakTest};
-pub use crate::special_casing::SpecialCaseMapping;
+pub use crate::special_casing::{
+    SpecialCaseCondition, SpecialCaseContext, SpecialCaseMapping,
+};
 pub use crate::unicode_data::{
     UnicodeData, UnicodeDataDecomposition, UnicodeDataDecompositionTag,
     UnicodeDataExpander, UnicodeDataNumeric,
 };
+pub use crate::unihan::{
+    parse_unihan, RadicalStroke, UnihanRecord, UnihanRecords,
+};
+pub use crate::variation_sequences::{
+    EmojiVariationSequence, ShapingEnvironment, StandardizedVariant,
+};
+pub use crate::vertical_orientation::VerticalOrientation;
 pub use crate::word_break::{WordBreak, WordBreakTest};
 
 macro_rules! err {
@@ -50,15 +77,26 @@ mod error;
 
 mod age;
 mod arabic_shaping;
+mod bidi_brackets;
 mod bidi_mirroring_glyph;
+mod blocks;
 mod case_folding;
+mod cjk_radicals;
+mod composition_exclusions;
 mod core_properties;
 mod east_asian_width;
 mod emoji_properties;
+mod emoji_sequences;
 mod grapheme_cluster_break;
+mod hangul_syllable_type;
+mod indic_positional_category;
+mod indic_syllabic_category;
 mod jamo_short_name;
 mod line_break;
 mod name_aliases;
+mod named_sequences;
+mod names_list;
+mod normalization_properties;
 mod prop_list;
 mod property_aliases;
 mod property_value_aliases;
@@ -67,4 +105,7 @@ mod scripts;
 mod sentence_break;
 mod special_casing;
 mod unicode_data;
+mod unihan;
+mod variation_sequences;
+mod vertical_orientation;
 mod word_break;
@@ -1,9 +1,46 @@
 use std::path::Path;
 use std::str::FromStr;
 
-use crate::common::{parse_break_test, UcdFile};
+use crate::common::{
+    parse_break_test, parse_codepoint_association, CodepointIter, Codepoints,
+    UcdFile, UcdFileByCodepoint,
+};
 use crate::error::Error;
 
+/// A single row in the `LineBreak.txt` file.
+///
+/// Note that codepoints that are not listed have the default value given by
+/// the `@missing` lines in this file, which can be retrieved with
+/// `parse_missing_defaults`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct LineBreak {
+    /// The codepoint or codepoint range for this entry.
+    pub codepoints: Codepoints,
+    /// The property value assigned to the codepoints in this entry.
+    pub value: String,
+}
+
+impl UcdFile for LineBreak {
+    fn relative_file_path() -> &'static Path {
+        Path::new("LineBreak.txt")
+    }
+}
+
+impl UcdFileByCodepoint for LineBreak {
+    fn codepoints(&self) -> CodepointIter {
+        self.codepoints.into_iter()
+    }
+}
+
+impl FromStr for LineBreak {
+    type Err = Error;
+
+    fn from_str(line: &str) -> Result<LineBreak, Error> {
+        let (codepoints, value) = parse_codepoint_association(line)?;
+        Ok(LineBreak { codepoints, value: value.to_string() })
+    }
+}
+
 /// A single row in the `auxiliary/LineBreakTest.txt` file.
 ///
 /// This file defines tests for the line break algorithm.
@@ -33,7 +70,23 @@ impl FromStr for LineBreakTest {
 
 #[cfg(test)]
 mod tests {
-    use super::LineBreakTest;
+    use super::{LineBreak, LineBreakTest};
+
+    #[test]
+    fn parse_single() {
+        let line = "0028;OP          # Ps         LEFT PARENTHESIS\n";
+        let row: LineBreak = line.parse().unwrap();
+        assert_eq!(row.codepoints, 0x0028);
+        assert_eq!(row.value, "OP");
+    }
+
+    #[test]
+    fn parse_range() {
+        let line = "1F1E6..1F1FF;RI  # So    [26] REGIONAL INDICATOR SYMBOL LETTER A..REGIONAL INDICATOR SYMBOL LETTER Z\n";
+        let row: LineBreak = line.parse().unwrap();
+        assert_eq!(row.codepoints, (0x1F1E6, 0x1F1FF));
+        assert_eq!(row.value, "RI");
+    }
 
     #[test]
     fn parse_test() {
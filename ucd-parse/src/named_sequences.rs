@@ -0,0 +1,120 @@
+use std::path::Path;
+use std::str::FromStr;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::common::{parse_codepoint_sequence, Codepoint, UcdFile};
+use crate::error::Error;
+
+/// A single row in the `NamedSequences.txt` file.
+///
+/// Each row gives a name to a sequence of two or more codepoints. These names
+/// share the namespace of character names, and may be used wherever a
+/// character name is accepted.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct NamedSequence {
+    /// The name of the sequence.
+    pub name: String,
+    /// The sequence of codepoints with this name.
+    pub codepoints: Vec<Codepoint>,
+}
+
+impl UcdFile for NamedSequence {
+    fn relative_file_path() -> &'static Path {
+        Path::new("NamedSequences.txt")
+    }
+}
+
+impl FromStr for NamedSequence {
+    type Err = Error;
+
+    fn from_str(line: &str) -> Result<NamedSequence, Error> {
+        let (name, codepoints) = parse_named_sequence(line)?;
+        Ok(NamedSequence { name, codepoints })
+    }
+}
+
+/// A single row in the `NamedSequencesProv.txt` file.
+///
+/// This file has the same format as `NamedSequences.txt`, but lists named
+/// sequences that have been provisionally accepted. Their names are not
+/// guaranteed to be stable.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ProvisionalNamedSequence {
+    /// The name of the sequence.
+    pub name: String,
+    /// The sequence of codepoints with this name.
+    pub codepoints: Vec<Codepoint>,
+}
+
+impl UcdFile for ProvisionalNamedSequence {
+    fn relative_file_path() -> &'static Path {
+        Path::new("NamedSequencesProv.txt")
+    }
+}
+
+impl FromStr for ProvisionalNamedSequence {
+    type Err = Error;
+
+    fn from_str(line: &str) -> Result<ProvisionalNamedSequence, Error> {
+        let (name, codepoints) = parse_named_sequence(line)?;
+        Ok(ProvisionalNamedSequence { name, codepoints })
+    }
+}
+
+/// Parses a single row of `NamedSequences.txt` or `NamedSequencesProv.txt`,
+/// which consists of a name and a non-empty sequence of codepoints.
+fn parse_named_sequence(
+    line: &str,
+) -> Result<(String, Vec<Codepoint>), Error> {
+    static PARTS: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(
+            r"(?x)
+            ^
+            (?P<name>[^;]+?)\s*;
+            \s*(?P<codepoints>[A-F0-9\s]+?)
+            (?:\s*\x23.*)?
+            $
+            ",
+        )
+        .unwrap()
+    });
+
+    let caps = match PARTS.captures(line.trim()) {
+        Some(caps) => caps,
+        None => return err!("invalid NamedSequences line: '{}'", line),
+    };
+    let codepoints = parse_codepoint_sequence(&caps["codepoints"])?;
+    if codepoints.is_empty() {
+        return err!("empty named sequence in line: '{}'", line);
+    }
+    Ok((caps["name"].to_string(), codepoints))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{NamedSequence, ProvisionalNamedSequence};
+
+    #[test]
+    fn parse() {
+        let line = "LATIN CAPITAL LETTER A WITH MACRON AND GRAVE;0100 0300\n";
+        let row: NamedSequence = line.parse().unwrap();
+        assert_eq!(row.name, "LATIN CAPITAL LETTER A WITH MACRON AND GRAVE");
+        assert_eq!(row.codepoints, vec![0x0100, 0x0300]);
+    }
+
+    #[test]
+    fn parse_provisional() {
+        let line = "TAMIL SYLLABLE KSSA;0B95 0BCD 0BB7\n";
+        let row: ProvisionalNamedSequence = line.parse().unwrap();
+        assert_eq!(row.name, "TAMIL SYLLABLE KSSA");
+        assert_eq!(row.codepoints.len(), 3);
+    }
+
+    #[test]
+    fn parse_invalid() {
+        let line = "LATIN CAPITAL LETTER A WITH MACRON AND GRAVE;\n";
+        assert!(line.parse::<NamedSequence>().is_err());
+    }
+}
@@ -0,0 +1,440 @@
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::common::{parse_codepoint_sequence, Codepoint};
+use crate::error::{Error, ErrorKind};
+
+/// Parse the `NamesList.txt` file in the given UCD directory.
+///
+/// Unlike most files in the UCD, `NamesList.txt` is not tabular. It is
+/// organized into blocks, which are divided into subheads, which contain
+/// entries for individual codepoints. Each entry may have any number of
+/// annotations, such as informative aliases and cross references. Titles,
+/// notices and comments are skipped.
+pub fn parse_names_list<P: AsRef<Path>>(
+    ucd_dir: P,
+) -> Result<Vec<NamesListBlock>, Error> {
+    let path = ucd_dir.as_ref().join("NamesList.txt");
+    let text = fs::read_to_string(&path).map_err(|e| Error {
+        kind: ErrorKind::Io(e),
+        line: None,
+        path: Some(path.clone()),
+    })?;
+    parse_names_list_str(&text).map_err(|mut err| {
+        err.path = Some(path);
+        err
+    })
+}
+
+/// A single block in the `NamesList.txt` file.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct NamesListBlock {
+    /// The first codepoint in this block.
+    pub start: Codepoint,
+    /// The last codepoint in this block.
+    pub end: Codepoint,
+    /// The name of this block as it appears in the code charts, e.g.,
+    /// `C0 Controls and Basic Latin (Basic Latin)`.
+    pub name: String,
+    /// The subheads in this block, in the order in which they appear.
+    pub subheads: Vec<NamesListSubhead>,
+}
+
+impl NamesListBlock {
+    /// Returns an iterator over all entries in this block, across all of its
+    /// subheads.
+    pub fn entries(&self) -> impl Iterator<Item = &NamesListEntry> {
+        self.subheads.iter().flat_map(|subhead| subhead.entries.iter())
+    }
+}
+
+/// A single subhead in the `NamesList.txt` file, which groups related
+/// entries within a block.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct NamesListSubhead {
+    /// The name of this subhead, e.g., `ASCII punctuation and symbols`.
+    ///
+    /// Entries that precede the first subhead of a block are put in a
+    /// subhead without a name.
+    pub name: Option<String>,
+    /// The entries in this subhead, in the order in which they appear.
+    pub entries: Vec<NamesListEntry>,
+}
+
+/// A single entry in the `NamesList.txt` file.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct NamesListEntry {
+    /// The codepoint of this entry.
+    pub codepoint: Codepoint,
+    /// The name of this entry. For codepoints without a name, this is a
+    /// label such as `<control>` or `<not a character>`.
+    pub name: String,
+    /// The annotations of this entry, in the order in which they appear.
+    pub annotations: Vec<NamesListAnnotation>,
+}
+
+/// An annotation on an entry in the `NamesList.txt` file.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum NamesListAnnotation {
+    /// An informative alias (`=`).
+    Alias(String),
+    /// A formal alias (`%`), which is a normative name alias also listed in
+    /// `NameAliases.txt`.
+    FormalAlias(String),
+    /// An informative note (`*`).
+    Comment(String),
+    /// A cross reference to a related codepoint (`x`). The name of the
+    /// referenced codepoint is only given for some cross references.
+    CrossReference {
+        /// The referenced codepoint.
+        codepoint: Codepoint,
+        /// The name of the referenced codepoint, if given.
+        name: Option<String>,
+    },
+    /// A canonical decomposition mapping (`:`).
+    CanonicalDecomposition(Vec<Codepoint>),
+    /// A compatibility decomposition mapping (`#`), along with its
+    /// formatting tag (e.g., `<noBreak>`), if any.
+    CompatibilityDecomposition {
+        /// The formatting tag of the decomposition, without angle brackets.
+        tag: Option<String>,
+        /// The decomposition mapping.
+        mapping: Vec<Codepoint>,
+    },
+    /// A standardized variation sequence (`~`) starting with this entry's
+    /// codepoint, along with a description of the variant.
+    Variation {
+        /// The variation sequence.
+        sequence: Vec<Codepoint>,
+        /// A description of the variant selected by the sequence.
+        description: String,
+    },
+}
+
+impl FromStr for NamesListAnnotation {
+    type Err = Error;
+
+    fn from_str(line: &str) -> Result<NamesListAnnotation, Error> {
+        let line = line.trim();
+        let (kind, rest) = match line.chars().next() {
+            Some(kind) => (kind, line[kind.len_utf8()..].trim()),
+            None => return err!("empty NamesList annotation"),
+        };
+        match kind {
+            '=' => Ok(NamesListAnnotation::Alias(rest.to_string())),
+            '%' => Ok(NamesListAnnotation::FormalAlias(rest.to_string())),
+            '*' => Ok(NamesListAnnotation::Comment(rest.to_string())),
+            'x' => parse_cross_reference(rest),
+            ':' => Ok(NamesListAnnotation::CanonicalDecomposition(
+                parse_codepoint_sequence(rest)?,
+            )),
+            '#' => {
+                let (tag, mapping) = if rest.starts_with('<') {
+                    match rest.find('>') {
+                        Some(i) => (Some(&rest[1..i]), &rest[i + 1..]),
+                        None => {
+                            return err!(
+                                "invalid NamesList decomposition: '{}'",
+                                line
+                            )
+                        }
+                    }
+                } else {
+                    (None, rest)
+                };
+                Ok(NamesListAnnotation::CompatibilityDecomposition {
+                    tag: tag.map(|t| t.to_string()),
+                    mapping: parse_codepoint_sequence(mapping)?,
+                })
+            }
+            '~' => {
+                static PARTS: Lazy<Regex> = Lazy::new(|| {
+                    Regex::new(
+                        r"^(?P<sequence>[A-F0-9]+(?:\s+[A-F0-9]+)+)\s*(?P<description>.*)$",
+                    )
+                    .unwrap()
+                });
+                let caps = match PARTS.captures(rest) {
+                    Some(caps) => caps,
+                    None => {
+                        return err!(
+                            "invalid NamesList variation sequence: '{}'",
+                            line
+                        )
+                    }
+                };
+                Ok(NamesListAnnotation::Variation {
+                    sequence: parse_codepoint_sequence(&caps["sequence"])?,
+                    description: caps["description"].to_string(),
+                })
+            }
+            _ => err!("unrecognized NamesList annotation: '{}'", line),
+        }
+    }
+}
+
+/// Parses the remainder of a cross reference line, which may be any of
+/// `XXXX`, `XXXX name`, `name - XXXX` or `(name - XXXX)`.
+fn parse_cross_reference(s: &str) -> Result<NamesListAnnotation, Error> {
+    static PARTS: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(
+            r"(?x)
+            ^
+            (?:
+                \(?(?P<name1>.+?)\s+-\s+(?P<cp1>[A-F0-9]+)\)?
+                |
+                (?P<cp2>[A-F0-9]+)(?:\s+(?P<name2>.+))?
+            )
+            $
+            ",
+        )
+        .unwrap()
+    });
+
+    let caps = match PARTS.captures(s) {
+        Some(caps) => caps,
+        None => return err!("invalid NamesList cross reference: '{}'", s),
+    };
+    let (cp, name) = match caps.name("cp1") {
+        Some(cp) => (cp, caps.name("name1")),
+        None => (caps.name("cp2").unwrap(), caps.name("name2")),
+    };
+    Ok(NamesListAnnotation::CrossReference {
+        codepoint: cp.as_str().parse()?,
+        name: name.map(|m| m.as_str().to_string()),
+    })
+}
+
+/// Parses the contents of a `NamesList.txt` file.
+fn parse_names_list_str(text: &str) -> Result<Vec<NamesListBlock>, Error> {
+    static BLOCK: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(
+            r"^@@\t(?P<start>[A-F0-9]+)\t(?P<name>[^\t]+)\t(?P<end>[A-F0-9]+)$",
+        )
+        .unwrap()
+    });
+    static ENTRY: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(r"^(?P<codepoint>[A-F0-9]{4,6})\t(?P<name>.+)$").unwrap()
+    });
+
+    let mut blocks: Vec<NamesListBlock> = vec![];
+    // Whether the previous line was part of a title or notice, in which case
+    // subsequent indented lines belong to it rather than to an entry.
+    let mut in_notice = false;
+    for (i, line) in text.lines().enumerate() {
+        let line_number = i as u64 + 1;
+        let set_line = |mut err: Error| {
+            err.line = Some(line_number);
+            err
+        };
+        let line = line.trim_end();
+        if line.is_empty() || line.starts_with(';') {
+            continue;
+        }
+        if line.starts_with("@@@") || line.starts_with("@@+") {
+            in_notice = true;
+        } else if line.starts_with("@@") {
+            let caps = match BLOCK.captures(line) {
+                Some(caps) => caps,
+                None => {
+                    return Err(set_line(Error::parse(format!(
+                        "invalid NamesList block header: '{}'",
+                        line
+                    ))))
+                }
+            };
+            blocks.push(NamesListBlock {
+                start: caps["start"].parse().map_err(set_line)?,
+                end: caps["end"].parse().map_err(set_line)?,
+                name: caps["name"].to_string(),
+                subheads: vec![],
+            });
+            in_notice = false;
+        } else if line.starts_with("@+") || line.starts_with("@~") {
+            in_notice = true;
+        } else if let Some(name) = line.strip_prefix('@') {
+            let block = match blocks.last_mut() {
+                Some(block) => block,
+                None => {
+                    return Err(set_line(Error::parse(format!(
+                        "NamesList subhead outside of a block: '{}'",
+                        line
+                    ))))
+                }
+            };
+            block.subheads.push(NamesListSubhead {
+                name: Some(name.trim().to_string()),
+                entries: vec![],
+            });
+            in_notice = false;
+        } else if line.starts_with('\t') {
+            if in_notice || line.trim_start().starts_with(';') {
+                continue;
+            }
+            let annotation: NamesListAnnotation =
+                line.parse().map_err(set_line)?;
+            let entry = blocks
+                .last_mut()
+                .and_then(|block| block.subheads.last_mut())
+                .and_then(|subhead| subhead.entries.last_mut());
+            match entry {
+                Some(entry) => entry.annotations.push(annotation),
+                None => {
+                    return Err(set_line(Error::parse(format!(
+                        "NamesList annotation without an entry: '{}'",
+                        line
+                    ))))
+                }
+            }
+        } else {
+            let caps = match ENTRY.captures(line) {
+                Some(caps) => caps,
+                None => {
+                    return Err(set_line(Error::parse(format!(
+                        "invalid NamesList line: '{}'",
+                        line
+                    ))))
+                }
+            };
+            let block = match blocks.last_mut() {
+                Some(block) => block,
+                None => {
+                    return Err(set_line(Error::parse(format!(
+                        "NamesList entry outside of a block: '{}'",
+                        line
+                    ))))
+                }
+            };
+            if block.subheads.is_empty() {
+                block
+                    .subheads
+                    .push(NamesListSubhead { name: None, entries: vec![] });
+            }
+            block.subheads.last_mut().unwrap().entries.push(NamesListEntry {
+                codepoint: caps["codepoint"].parse().map_err(set_line)?,
+                name: caps["name"].to_string(),
+                annotations: vec![],
+            });
+            in_notice = false;
+        }
+    }
+    Ok(blocks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_names_list_str, NamesListAnnotation};
+
+    const NAMES_LIST: &str = "\
+;\tThis file is semi-automatically derived from UnicodeData.txt
+@@@\tThe Unicode Standard 14.0.0
+@@@+\tU14M210802.lst
+\tUnicode 14.0.0 final names list.
+@@\t0000\tC0 Controls and Basic Latin (Basic Latin)\t007F
+@@+
+\t* an ASCII compatible block
+@\t\tC0 controls
+@+\t\tAlias names are those for ISO/IEC 6429:1992.
+0000\t<control>
+\t= NULL
+0020\tSPACE
+\t* sometimes considered a control code
+\tx (no-break space - 00A0)
+\tx 3000
+@\t\tASCII digits
+0030\tDIGIT ZERO
+\t~ 0030 FE00 short diagonal stroke form
+@@\t0080\tC1 Controls and Latin-1 Supplement (Latin-1 Supplement)\t00FF
+00A0\tNO-BREAK SPACE
+\t* commonly abbreviated as NBSP
+\t# <noBreak> 0020
+00C0\tLATIN CAPITAL LETTER A WITH GRAVE
+\t: 0041 0300
+";
+
+    #[test]
+    fn parse_blocks() {
+        let blocks = parse_names_list_str(NAMES_LIST).unwrap();
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[0].start, 0x0000);
+        assert_eq!(blocks[0].end, 0x007F);
+        assert_eq!(
+            blocks[0].name,
+            "C0 Controls and Basic Latin (Basic Latin)"
+        );
+        assert_eq!(blocks[0].subheads.len(), 2);
+        assert_eq!(blocks[0].subheads[0].name.as_deref(), Some("C0 controls"));
+        assert_eq!(blocks[0].entries().count(), 3);
+
+        // Entries before the first subhead go in an unnamed subhead.
+        assert_eq!(blocks[1].subheads.len(), 1);
+        assert_eq!(blocks[1].subheads[0].name, None);
+        assert_eq!(blocks[1].subheads[0].entries.len(), 2);
+    }
+
+    #[test]
+    fn parse_annotations() {
+        let blocks = parse_names_list_str(NAMES_LIST).unwrap();
+        let space = &blocks[0].subheads[0].entries[1];
+        assert_eq!(space.codepoint, 0x0020);
+        assert_eq!(space.name, "SPACE");
+        assert_eq!(
+            space.annotations,
+            vec![
+                NamesListAnnotation::Comment(
+                    "sometimes considered a control code".to_string()
+                ),
+                NamesListAnnotation::CrossReference {
+                    codepoint: "00A0".parse().unwrap(),
+                    name: Some("no-break space".to_string()),
+                },
+                NamesListAnnotation::CrossReference {
+                    codepoint: "3000".parse().unwrap(),
+                    name: None,
+                },
+            ]
+        );
+
+        let nbsp = &blocks[1].subheads[0].entries[0];
+        assert_eq!(
+            nbsp.annotations[1],
+            NamesListAnnotation::CompatibilityDecomposition {
+                tag: Some("noBreak".to_string()),
+                mapping: vec!["0020".parse().unwrap()],
+            }
+        );
+    }
+
+    #[test]
+    fn parse_alias() {
+        let ann: NamesListAnnotation = "\t= NULL".parse().unwrap();
+        assert_eq!(ann, NamesListAnnotation::Alias("NULL".to_string()));
+    }
+
+    #[test]
+    fn parse_variation() {
+        let ann: NamesListAnnotation =
+            "\t~ 0030 FE00 short diagonal stroke form".parse().unwrap();
+        assert_eq!(
+            ann,
+            NamesListAnnotation::Variation {
+                sequence: vec![
+                    "0030".parse().unwrap(),
+                    "FE00".parse().unwrap()
+                ],
+                description: "short diagonal stroke form".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn parse_annotation_without_entry() {
+        let text = "@@\t0000\tBasic Latin\t007F\n\t= NULL\n";
+        let err = parse_names_list_str(text).unwrap_err();
+        assert_eq!(err.line(), Some(2));
+    }
+}
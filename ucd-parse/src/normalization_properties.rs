@@ -0,0 +1,173 @@
+use std::path::Path;
+use std::str::FromStr;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::common::{
+    parse_codepoint_sequence, Codepoint, CodepointIter, Codepoints, UcdFile,
+    UcdFileByCodepoint,
+};
+use crate::error::Error;
+
+/// A single row in the `DerivedNormalizationProps.txt` file.
+///
+/// Unlike most other property files, this file mixes several kinds of
+/// records. Binary properties (such as `Full_Composition_Exclusion`) only
+/// list a property name, while the quick check properties (such as
+/// `NFC_QC`) and the string properties (such as `NFKC_CF`) also include a
+/// value.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct NormalizationProperty {
+    /// The codepoint or codepoint range for this entry.
+    pub codepoints: Codepoints,
+    /// The property name assigned to the codepoints in this entry.
+    pub property: String,
+    /// The value of the property for the codepoints in this entry.
+    pub value: NormalizationPropertyValue,
+}
+
+impl UcdFile for NormalizationProperty {
+    fn relative_file_path() -> &'static Path {
+        Path::new("DerivedNormalizationProps.txt")
+    }
+}
+
+impl UcdFileByCodepoint for NormalizationProperty {
+    fn codepoints(&self) -> CodepointIter {
+        self.codepoints.into_iter()
+    }
+}
+
+impl FromStr for NormalizationProperty {
+    type Err = Error;
+
+    fn from_str(line: &str) -> Result<NormalizationProperty, Error> {
+        static PARTS: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(
+                r"(?x)
+                ^
+                \s*(?P<codepoints>[^\s;]+)\s*;
+                \s*(?P<property>[^\s;\x23]+)\s*
+                (?:;(?P<value>[^;\x23]*))?
+                (?:\x23.*)?
+                $
+                ",
+            )
+            .unwrap()
+        });
+
+        let caps = match PARTS.captures(line.trim()) {
+            Some(caps) => caps,
+            None => {
+                return err!(
+                    "invalid DerivedNormalizationProps line: '{}'",
+                    line
+                )
+            }
+        };
+        let property = caps["property"].to_string();
+        let value = match caps.name("value") {
+            None => NormalizationPropertyValue::Binary,
+            Some(m) if is_string_property(&property) => {
+                let cps = parse_codepoint_sequence(m.as_str())?;
+                NormalizationPropertyValue::Mapping(cps)
+            }
+            Some(m) => {
+                let value = m.as_str().trim();
+                if value.is_empty() {
+                    return err!(
+                        "missing value for {} in \
+                         DerivedNormalizationProps line: '{}'",
+                        property,
+                        line
+                    );
+                }
+                NormalizationPropertyValue::Enumerated(value.to_string())
+            }
+        };
+        Ok(NormalizationProperty {
+            codepoints: caps["codepoints"].parse()?,
+            property,
+            value,
+        })
+    }
+}
+
+/// The value of a property in `DerivedNormalizationProps.txt`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub enum NormalizationPropertyValue {
+    /// The codepoints in the entry have the (binary) property.
+    #[default]
+    Binary,
+    /// An enumerated property value, such as `N` or `M` for the quick check
+    /// properties.
+    Enumerated(String),
+    /// A string property value, given as a possibly empty sequence of
+    /// codepoints. For example, this is used by `NFKC_CF`.
+    Mapping(Vec<Codepoint>),
+}
+
+/// Returns true if and only if the given property in
+/// `DerivedNormalizationProps.txt` has values that are sequences of
+/// codepoints.
+fn is_string_property(property: &str) -> bool {
+    matches!(property, "FC_NFKC" | "NFKC_CF" | "NFKC_SCF")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{NormalizationProperty, NormalizationPropertyValue};
+
+    #[test]
+    fn parse_binary() {
+        let line = "0958..095F    ; Full_Composition_Exclusion # Lo   [8] DEVANAGARI LETTER QA..DEVANAGARI LETTER YYA\n";
+        let row: NormalizationProperty = line.parse().unwrap();
+        assert_eq!(row.codepoints, (0x0958, 0x095F));
+        assert_eq!(row.property, "Full_Composition_Exclusion");
+        assert_eq!(row.value, NormalizationPropertyValue::Binary);
+    }
+
+    #[test]
+    fn parse_enumerated() {
+        let line = "0340..0341    ; NFC_QC; N # Mn   [2] COMBINING GRAVE TONE MARK..COMBINING ACUTE TONE MARK\n";
+        let row: NormalizationProperty = line.parse().unwrap();
+        assert_eq!(row.codepoints, (0x0340, 0x0341));
+        assert_eq!(row.property, "NFC_QC");
+        assert_eq!(
+            row.value,
+            NormalizationPropertyValue::Enumerated("N".to_string())
+        );
+    }
+
+    #[test]
+    fn parse_mapping() {
+        let line = "00A8          ; NFKC_CF; 0020 0308 # Sk       DIAERESIS\n";
+        let row: NormalizationProperty = line.parse().unwrap();
+        assert_eq!(row.codepoints, 0x00A8);
+        assert_eq!(row.property, "NFKC_CF");
+        assert_eq!(
+            row.value,
+            NormalizationPropertyValue::Mapping(vec![
+                "0020".parse().unwrap(),
+                "0308".parse().unwrap(),
+            ])
+        );
+    }
+
+    #[test]
+    fn parse_empty_mapping() {
+        let line = "00AD          ; NFKC_CF;  # Cf       SOFT HYPHEN\n";
+        let row: NormalizationProperty = line.parse().unwrap();
+        assert_eq!(row.codepoints, 0x00AD);
+        assert_eq!(row.property, "NFKC_CF");
+        assert_eq!(row.value, NormalizationPropertyValue::Mapping(vec![]));
+    }
+
+    #[test]
+    fn parse_missing_enumerated_value() {
+        let line =
+            "0340          ; NFC_QC; # Mn       COMBINING GRAVE TONE MARK\n";
+        assert!(line.parse::<NormalizationProperty>().is_err());
+    }
+}
@@ -27,9 +27,22 @@ pub struct SpecialCaseMapping {
     pub uppercase: Vec<Codepoint>,
     /// A list of language specific conditions, see `SpecialCasing.txt` for
     /// more details.
+    ///
+    /// Use `parse_conditions` to get the structured form of these conditions.
     pub conditions: Vec<String>,
 }
 
+impl SpecialCaseMapping {
+    /// Parse the conditions of this mapping into their structured form.
+    ///
+    /// All of the conditions must be satisfied for this mapping to apply.
+    pub fn parse_conditions(
+        &self,
+    ) -> Result<Vec<SpecialCaseCondition>, Error> {
+        self.conditions.iter().map(|c| c.parse()).collect()
+    }
+}
+
 impl UcdFile for SpecialCaseMapping {
     fn relative_file_path() -> &'static Path {
         Path::new("SpecialCasing.txt")
@@ -84,9 +97,97 @@ impl FromStr for SpecialCaseMapping {
     }
 }
 
+/// A single condition on a mapping in the `SpecialCasing.txt` file.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum SpecialCaseCondition {
+    /// The mapping only applies to text in the given language, which is
+    /// identified by its ISO 639 code (e.g., `tr`, `az` or `lt`).
+    Language(String),
+    /// The mapping only applies when the given casing context holds.
+    Context(SpecialCaseContext),
+    /// The mapping only applies when the given casing context does not hold.
+    /// This corresponds to the conditions prefixed with `Not_`.
+    NotContext(SpecialCaseContext),
+    /// A condition that is not recognized, such as a casing context that was
+    /// added in a later version of Unicode. This holds the condition as it
+    /// appears in `SpecialCasing.txt`.
+    Other(String),
+}
+
+impl FromStr for SpecialCaseCondition {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<SpecialCaseCondition, Error> {
+        if let Some(context) = s.strip_prefix("Not_") {
+            if let Ok(context) = context.parse() {
+                return Ok(SpecialCaseCondition::NotContext(context));
+            }
+        } else if let Ok(context) = s.parse() {
+            return Ok(SpecialCaseCondition::Context(context));
+        }
+        let is_language = (2..=3).contains(&s.len())
+            && s.bytes().all(|b| b.is_ascii_lowercase());
+        if is_language {
+            Ok(SpecialCaseCondition::Language(s.to_string()))
+        } else {
+            Ok(SpecialCaseCondition::Other(s.to_string()))
+        }
+    }
+}
+
+/// A casing context, as defined in Table 3-17 of the Unicode standard.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SpecialCaseContext {
+    /// The codepoint is preceded by a cased letter and is not followed by
+    /// one (ignoring case-ignorable codepoints in both directions).
+    FinalSigma,
+    /// There is a Soft_Dotted codepoint before the codepoint, with no
+    /// intervening codepoint of combining class 0 or 230.
+    AfterSoftDotted,
+    /// The codepoint is followed by a codepoint of combining class 230, with
+    /// no intervening codepoint of combining class 0 or 230.
+    MoreAbove,
+    /// The codepoint is followed by U+0307 COMBINING DOT ABOVE, with no
+    /// intervening codepoint of combining class 0 or 230.
+    BeforeDot,
+    /// There is an uppercase I before the codepoint, with no intervening
+    /// codepoint of combining class 0 or 230.
+    AfterI,
+}
+
+impl SpecialCaseContext {
+    /// Return the name of this context as it appears in `SpecialCasing.txt`.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            SpecialCaseContext::FinalSigma => "Final_Sigma",
+            SpecialCaseContext::AfterSoftDotted => "After_Soft_Dotted",
+            SpecialCaseContext::MoreAbove => "More_Above",
+            SpecialCaseContext::BeforeDot => "Before_Dot",
+            SpecialCaseContext::AfterI => "After_I",
+        }
+    }
+}
+
+impl FromStr for SpecialCaseContext {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<SpecialCaseContext, Error> {
+        match s {
+            "Final_Sigma" => Ok(SpecialCaseContext::FinalSigma),
+            "After_Soft_Dotted" => Ok(SpecialCaseContext::AfterSoftDotted),
+            "More_Above" => Ok(SpecialCaseContext::MoreAbove),
+            "Before_Dot" => Ok(SpecialCaseContext::BeforeDot),
+            "After_I" => Ok(SpecialCaseContext::AfterI),
+            unknown => err!("unknown casing context: '{}'", unknown),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::SpecialCaseMapping;
+    use super::{
+        SpecialCaseCondition, SpecialCaseContext, SpecialCaseMapping,
+    };
 
     #[test]
     fn parse_no_conds() {
@@ -109,4 +210,38 @@ mod tests {
         assert_eq!(row.uppercase, vec![0x0307]);
         assert_eq!(row.conditions, vec!["tr", "After_I"]);
     }
+
+    #[test]
+    fn parse_structured_conds() {
+        let line = "0049; 0131; 0049; 0049; tr Not_Before_Dot; # LATIN CAPITAL LETTER I\n";
+        let row: SpecialCaseMapping = line.parse().unwrap();
+        assert_eq!(
+            row.parse_conditions().unwrap(),
+            vec![
+                SpecialCaseCondition::Language("tr".to_string()),
+                SpecialCaseCondition::NotContext(
+                    SpecialCaseContext::BeforeDot
+                ),
+            ]
+        );
+
+        let line = "03A3; 03C2; 03A3; 03A3; Final_Sigma; # GREEK CAPITAL LETTER SIGMA\n";
+        let row: SpecialCaseMapping = line.parse().unwrap();
+        assert_eq!(
+            row.parse_conditions().unwrap(),
+            vec![SpecialCaseCondition::Context(
+                SpecialCaseContext::FinalSigma
+            )]
+        );
+    }
+
+    #[test]
+    fn parse_unknown_cond() {
+        let line = "03A3; 03C2; 03A3; 03A3; Not_Final; # GREEK CAPITAL LETTER SIGMA\n";
+        let row: SpecialCaseMapping = line.parse().unwrap();
+        assert_eq!(
+            row.parse_conditions().unwrap(),
+            vec![SpecialCaseCondition::Other("Not_Final".to_string())]
+        );
+    }
 }
@@ -0,0 +1,325 @@
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::vec;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::cjk_radicals::{format_radical_number, parse_radical_number};
+use crate::common::{Codepoint, CodepointIter, UcdLineParser};
+use crate::error::{Error, ErrorKind};
+
+/// The names of the files that make up the Unihan database.
+const UNIHAN_FILES: &[&str] = &[
+    "Unihan_DictionaryIndices.txt",
+    "Unihan_DictionaryLikeData.txt",
+    "Unihan_IRGSources.txt",
+    "Unihan_NumericValues.txt",
+    "Unihan_OtherMappings.txt",
+    "Unihan_RadicalStrokeCounts.txt",
+    "Unihan_Readings.txt",
+    "Unihan_Variants.txt",
+];
+
+/// The Unihan fields whose values are a space separated list of readings.
+const READING_FIELDS: &[&str] = &[
+    "kCantonese",
+    "kHangul",
+    "kHanyuPinlu",
+    "kHanyuPinyin",
+    "kJapaneseKun",
+    "kJapaneseOn",
+    "kKorean",
+    "kMandarin",
+    "kTang",
+    "kTGHZ2013",
+    "kVietnamese",
+    "kXHC1983",
+];
+
+/// Return an iterator over the records of all of the `Unihan_*.txt` files in
+/// the given directory.
+///
+/// The Unihan database is distributed separately from the rest of the UCD
+/// (as `Unihan.zip`). Its files are looked up in the given directory and in
+/// a `Unihan` sub-directory of it. Files that cannot be found in either
+/// location are skipped, but an error is returned if none of them can be
+/// found.
+///
+/// Since the Unihan database is large, records are parsed lazily, one file
+/// at a time. Callers interested in only some fields should filter the
+/// records as they are produced.
+pub fn parse_unihan<P: AsRef<Path>>(
+    ucd_dir: P,
+) -> Result<UnihanRecords, Error> {
+    let ucd_dir = ucd_dir.as_ref();
+    let paths: Vec<PathBuf> = UNIHAN_FILES
+        .iter()
+        .filter_map(|name| unihan_file_path(ucd_dir, name))
+        .collect();
+    if paths.is_empty() {
+        let path = ucd_dir.join(UNIHAN_FILES[0]);
+        let kind = std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            "could not find any Unihan_*.txt files",
+        );
+        return Err(Error {
+            kind: ErrorKind::Io(kind),
+            line: None,
+            path: Some(path),
+        });
+    }
+    Ok(UnihanRecords { paths: paths.into_iter(), parser: None })
+}
+
+/// An iterator over the records of the Unihan database, as returned by
+/// `parse_unihan`.
+#[derive(Debug)]
+pub struct UnihanRecords {
+    paths: vec::IntoIter<PathBuf>,
+    parser: Option<UcdLineParser<File, UnihanRecord>>,
+}
+
+impl Iterator for UnihanRecords {
+    type Item = Result<UnihanRecord, Error>;
+
+    fn next(&mut self) -> Option<Result<UnihanRecord, Error>> {
+        loop {
+            if let Some(ref mut parser) = self.parser {
+                if let Some(result) = parser.next() {
+                    return Some(result);
+                }
+            }
+            let path = self.paths.next()?;
+            match UcdLineParser::from_path(path) {
+                Ok(parser) => self.parser = Some(parser),
+                Err(err) => return Some(Err(err)),
+            }
+        }
+    }
+}
+
+/// Returns the path to the given Unihan file, if it exists.
+fn unihan_file_path(ucd_dir: &Path, name: &str) -> Option<PathBuf> {
+    let candidates = [ucd_dir.join(name), ucd_dir.join("Unihan").join(name)];
+    candidates.iter().find(|path| path.exists()).cloned()
+}
+
+/// A single row in one of the `Unihan_*.txt` files.
+///
+/// Unlike the rest of the UCD, each row of the Unihan database has the form
+/// `U+XXXX<TAB>kField<TAB>value`, and associates exactly one codepoint with
+/// the value of exactly one field. The value is kept as it appears in the
+/// file, but typed accessors are provided for some common fields.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct UnihanRecord {
+    /// The codepoint corresponding to this row.
+    pub codepoint: Codepoint,
+    /// The name of the field, e.g., `kDefinition` or `kMandarin`.
+    pub field: String,
+    /// The raw value of the field.
+    pub value: String,
+}
+
+impl UnihanRecord {
+    /// Returns an iterator over the codepoints in this record. There is
+    /// always exactly one.
+    pub fn codepoints(&self) -> CodepointIter {
+        self.codepoint.into_iter()
+    }
+
+    /// Returns the English definition of this codepoint if this is a
+    /// `kDefinition` record.
+    pub fn definition(&self) -> Option<&str> {
+        if self.field == "kDefinition" {
+            Some(&self.value)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the readings of this codepoint if this record is for one of
+    /// the reading fields, such as `kMandarin` or `kJapaneseOn`.
+    ///
+    /// Each reading is returned as it appears in the file. For some fields,
+    /// such as `kHanyuPinyin`, a reading includes additional information
+    /// such as a source reference.
+    pub fn readings(&self) -> Option<Vec<&str>> {
+        if READING_FIELDS.contains(&&*self.field) {
+            Some(self.value.split_whitespace().collect())
+        } else {
+            None
+        }
+    }
+
+    /// Returns the total stroke counts of this codepoint if this is a
+    /// `kTotalStrokes` record.
+    ///
+    /// When there are two counts, the first is the preferred count for
+    /// China and the second is the preferred count for other locales.
+    pub fn total_strokes(&self) -> Result<Option<Vec<u8>>, Error> {
+        if self.field != "kTotalStrokes" {
+            return Ok(None);
+        }
+        let mut counts = vec![];
+        for count in self.value.split_whitespace() {
+            match count.parse() {
+                Ok(count) => counts.push(count),
+                Err(_) => {
+                    return err!("invalid kTotalStrokes value: '{}'", count)
+                }
+            }
+        }
+        Ok(Some(counts))
+    }
+
+    /// Returns the radical-stroke counts of this codepoint if this is a
+    /// `kRSUnicode` record.
+    ///
+    /// The first radical-stroke count is the one used to order this
+    /// codepoint in the code charts.
+    pub fn radical_strokes(
+        &self,
+    ) -> Result<Option<Vec<RadicalStroke>>, Error> {
+        if self.field != "kRSUnicode" {
+            return Ok(None);
+        }
+        let mut counts = vec![];
+        for count in self.value.split_whitespace() {
+            counts.push(count.parse()?);
+        }
+        Ok(Some(counts))
+    }
+}
+
+impl FromStr for UnihanRecord {
+    type Err = Error;
+
+    fn from_str(line: &str) -> Result<UnihanRecord, Error> {
+        static PARTS: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(
+                r"(?x)
+                ^
+                U\+(?P<codepoint>[A-F0-9]+)\t
+                (?P<field>k[A-Za-z0-9_]+)\t
+                (?P<value>.+)
+                $
+                ",
+            )
+            .unwrap()
+        });
+
+        let line = line.trim_end_matches(&['\r', '\n'][..]);
+        let caps = match PARTS.captures(line) {
+            Some(caps) => caps,
+            None => return err!("invalid Unihan line: '{}'", line),
+        };
+        Ok(UnihanRecord {
+            codepoint: caps["codepoint"].parse()?,
+            field: caps["field"].to_string(),
+            value: caps["value"].to_string(),
+        })
+    }
+}
+
+/// A radical-stroke count, as used by the `kRSUnicode` field.
+///
+/// A radical-stroke count has the form `radical.strokes`, e.g., `90'.3`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct RadicalStroke {
+    /// The KangXi radical number, from 1 to 214.
+    pub radical: u8,
+    /// The number of apostrophes following the radical number. A value of `1`
+    /// indicates a Chinese simplified form of the radical.
+    pub simplified: u8,
+    /// The number of residual strokes, i.e., strokes in addition to those of
+    /// the radical. This may be negative.
+    pub strokes: i8,
+}
+
+impl RadicalStroke {
+    /// Returns the radical number of this count as it appears in the Unihan
+    /// database, including any apostrophes, e.g., `90'`.
+    pub fn radical_number(&self) -> String {
+        format_radical_number(self.radical, self.simplified)
+    }
+}
+
+impl FromStr for RadicalStroke {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<RadicalStroke, Error> {
+        let (radical, strokes) = match s.find('.') {
+            Some(i) => (&s[..i], &s[i + 1..]),
+            None => return err!("invalid radical-stroke count: '{}'", s),
+        };
+        let (radical, simplified) = parse_radical_number(radical)?;
+        let strokes = match strokes.parse() {
+            Ok(strokes) => strokes,
+            Err(_) => return err!("invalid radical-stroke count: '{}'", s),
+        };
+        Ok(RadicalStroke { radical, simplified, strokes })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RadicalStroke, UnihanRecord};
+
+    #[test]
+    fn parse_definition() {
+        let line = "U+4E00\tkDefinition\tone; a, an; alone\n";
+        let row: UnihanRecord = line.parse().unwrap();
+        assert_eq!(row.codepoint, 0x4E00);
+        assert_eq!(row.field, "kDefinition");
+        assert_eq!(row.definition(), Some("one; a, an; alone"));
+        assert_eq!(row.readings(), None);
+    }
+
+    #[test]
+    fn parse_readings() {
+        let line = "U+5525\tkCantonese\tjyu4 jyu6\n";
+        let row: UnihanRecord = line.parse().unwrap();
+        assert_eq!(row.codepoint, 0x5525);
+        assert_eq!(row.readings(), Some(vec!["jyu4", "jyu6"]));
+        assert_eq!(row.definition(), None);
+    }
+
+    #[test]
+    fn parse_total_strokes() {
+        let line = "U+20000\tkTotalStrokes\t2 3\n";
+        let row: UnihanRecord = line.parse().unwrap();
+        assert_eq!(row.codepoint, 0x20000);
+        assert_eq!(row.total_strokes().unwrap(), Some(vec![2, 3]));
+    }
+
+    #[test]
+    fn parse_radical_strokes() {
+        let line = "U+4E2A\tkRSUnicode\t2.2 9'.-1\n";
+        let row: UnihanRecord = line.parse().unwrap();
+        assert_eq!(
+            row.radical_strokes().unwrap(),
+            Some(vec![
+                RadicalStroke { radical: 2, simplified: 0, strokes: 2 },
+                RadicalStroke { radical: 9, simplified: 1, strokes: -1 },
+            ])
+        );
+    }
+
+    #[test]
+    fn parse_out_of_range_radical_strokes() {
+        let line = "U+4E2A\tkRSUnicode\t2.128\n";
+        let row: UnihanRecord = line.parse().unwrap();
+        assert!(row.radical_strokes().is_err());
+        let line = "U+4E2A\tkRSUnicode\t2.-129\n";
+        let row: UnihanRecord = line.parse().unwrap();
+        assert!(row.radical_strokes().is_err());
+    }
+
+    #[test]
+    fn parse_invalid() {
+        let line = "U+4E00 kDefinition one\n";
+        assert!(line.parse::<UnihanRecord>().is_err());
+    }
+}
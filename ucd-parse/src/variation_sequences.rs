@@ -0,0 +1,235 @@
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::common::{
+    emoji_file_path, Codepoint, CodepointIter, UcdFile, UcdFileByCodepoint,
+};
+use crate::error::Error;
+
+/// A single row in the `StandardizedVariants.txt` file.
+///
+/// Each row describes a variation sequence, which consists of a base
+/// character followed by a variation selector, along with a description of
+/// the glyph variant it selects.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct StandardizedVariant {
+    /// The base character of the variation sequence.
+    pub base: Codepoint,
+    /// The variation selector of the variation sequence.
+    pub selector: Codepoint,
+    /// A description of the glyph variant selected by this sequence.
+    pub description: String,
+    /// The shaping environments in which this sequence applies. When empty,
+    /// the sequence applies in all shaping environments.
+    pub environments: Vec<ShapingEnvironment>,
+}
+
+impl UcdFile for StandardizedVariant {
+    fn relative_file_path() -> &'static Path {
+        Path::new("StandardizedVariants.txt")
+    }
+}
+
+impl UcdFileByCodepoint for StandardizedVariant {
+    fn codepoints(&self) -> CodepointIter {
+        self.base.into_iter()
+    }
+}
+
+impl FromStr for StandardizedVariant {
+    type Err = Error;
+
+    fn from_str(line: &str) -> Result<StandardizedVariant, Error> {
+        static PARTS: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(
+                r"(?x)
+                ^
+                \s*(?P<base>[A-F0-9]+)\s+(?P<selector>[A-F0-9]+)\s*;
+                \s*(?P<description>[^;]*?)\s*;
+                \s*(?P<environments>[^;\x23]*?)\s*
+                (?:\x23.*)?
+                $
+                ",
+            )
+            .unwrap()
+        });
+
+        let caps = match PARTS.captures(line.trim()) {
+            Some(caps) => caps,
+            None => {
+                return err!("invalid StandardizedVariants line: '{}'", line)
+            }
+        };
+        let mut environments = vec![];
+        for env in caps["environments"].split_whitespace() {
+            environments.push(env.parse()?);
+        }
+        Ok(StandardizedVariant {
+            base: caps["base"].parse()?,
+            selector: caps["selector"].parse()?,
+            description: caps["description"].to_string(),
+            environments,
+        })
+    }
+}
+
+/// A shaping environment in which a standardized variant applies.
+///
+/// These are only used by variation sequences for scripts with cursive
+/// joining behavior, such as Mongolian.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ShapingEnvironment {
+    /// The isolated form.
+    Isolate,
+    /// The initial form.
+    Initial,
+    /// The medial form.
+    Medial,
+    /// The final form.
+    Final,
+}
+
+impl ShapingEnvironment {
+    /// Returns the name of this shaping environment as it appears in
+    /// `StandardizedVariants.txt`.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            ShapingEnvironment::Isolate => "isolate",
+            ShapingEnvironment::Initial => "initial",
+            ShapingEnvironment::Medial => "medial",
+            ShapingEnvironment::Final => "final",
+        }
+    }
+}
+
+impl FromStr for ShapingEnvironment {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<ShapingEnvironment, Error> {
+        match s {
+            "isolate" => Ok(ShapingEnvironment::Isolate),
+            "initial" => Ok(ShapingEnvironment::Initial),
+            "medial" => Ok(ShapingEnvironment::Medial),
+            "final" => Ok(ShapingEnvironment::Final),
+            _ => err!("unrecognized shaping environment: '{}'", s),
+        }
+    }
+}
+
+/// A single row in the `emoji-variation-sequences.txt` file.
+///
+/// Each row describes a variation sequence that requests either the text or
+/// the emoji presentation of its base character.
+///
+/// Note that `emoji-variation-sequences.txt` is not formally part of the
+/// Unicode Character Database. You can download the Emoji data files
+/// separately here: https://unicode.org/Public/emoji/
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct EmojiVariationSequence {
+    /// The base character of the variation sequence.
+    pub base: Codepoint,
+    /// The variation selector of the variation sequence, which is either
+    /// U+FE0E (text style) or U+FE0F (emoji style).
+    pub selector: Codepoint,
+    /// A description of this sequence, i.e., `text style` or `emoji style`.
+    pub description: String,
+}
+
+impl UcdFile for EmojiVariationSequence {
+    fn relative_file_path() -> &'static Path {
+        Path::new("emoji/emoji-variation-sequences.txt")
+    }
+
+    fn file_path<P: AsRef<Path>>(ucd_dir: P) -> PathBuf {
+        emoji_file_path(ucd_dir.as_ref(), Self::relative_file_path())
+    }
+}
+
+impl UcdFileByCodepoint for EmojiVariationSequence {
+    fn codepoints(&self) -> CodepointIter {
+        self.base.into_iter()
+    }
+}
+
+impl FromStr for EmojiVariationSequence {
+    type Err = Error;
+
+    fn from_str(line: &str) -> Result<EmojiVariationSequence, Error> {
+        static PARTS: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(
+                r"(?x)
+                ^
+                \s*(?P<base>[A-F0-9]+)\s+(?P<selector>[A-F0-9]+)\s*;
+                \s*(?P<description>[^;\x23]*?)\s*;?
+                \s*(?:\x23.*)?
+                $
+                ",
+            )
+            .unwrap()
+        });
+
+        let caps = match PARTS.captures(line.trim()) {
+            Some(caps) => caps,
+            None => {
+                return err!(
+                    "invalid emoji-variation-sequences line: '{}'",
+                    line
+                )
+            }
+        };
+        Ok(EmojiVariationSequence {
+            base: caps["base"].parse()?,
+            selector: caps["selector"].parse()?,
+            description: caps["description"].to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        EmojiVariationSequence, ShapingEnvironment, StandardizedVariant,
+    };
+
+    #[test]
+    fn parse_standardized_variant() {
+        let line = "0030 FE00; short diagonal stroke form; # DIGIT ZERO\n";
+        let row: StandardizedVariant = line.parse().unwrap();
+        assert_eq!(row.base, 0x0030);
+        assert_eq!(row.selector, 0xFE00);
+        assert_eq!(row.description, "short diagonal stroke form");
+        assert!(row.environments.is_empty());
+    }
+
+    #[test]
+    fn parse_standardized_variant_environments() {
+        let line =
+            "1820 180B; second form; medial final # MONGOLIAN LETTER A\n";
+        let row: StandardizedVariant = line.parse().unwrap();
+        assert_eq!(row.base, 0x1820);
+        assert_eq!(row.selector, 0x180B);
+        assert_eq!(row.description, "second form");
+        assert_eq!(
+            row.environments,
+            vec![ShapingEnvironment::Medial, ShapingEnvironment::Final]
+        );
+    }
+
+    #[test]
+    fn parse_standardized_variant_invalid_environment() {
+        let line = "1820 180B; second form; middle # MONGOLIAN LETTER A\n";
+        assert!(line.parse::<StandardizedVariant>().is_err());
+    }
+
+    #[test]
+    fn parse_emoji_variation_sequence() {
+        let line = "0023 FE0F  ; emoji style; # (1.1) NUMBER SIGN\n";
+        let row: EmojiVariationSequence = line.parse().unwrap();
+        assert_eq!(row.base, 0x0023);
+        assert_eq!(row.selector, 0xFE0F);
+        assert_eq!(row.description, "emoji style");
+    }
+}
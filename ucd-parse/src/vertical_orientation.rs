@@ -0,0 +1,68 @@
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::common::{
+    parse_codepoint_association, CodepointIter, Codepoints, UcdFile,
+    UcdFileByCodepoint,
+};
+use crate::error::Error;
+
+/// A single row in the `VerticalOrientation.txt` file.
+///
+/// This file gives the values of the Vertical_Orientation property. Note that
+/// codepoints that are not listed have the default value given by the
+/// `@missing` line in this file, which can be retrieved with
+/// `parse_missing_defaults`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct VerticalOrientation {
+    /// The codepoint or codepoint range for this entry.
+    pub codepoints: Codepoints,
+    /// The Vertical_Orientation of the codepoints in this entry, as an
+    /// abbreviation (e.g., `U` or `Tr`).
+    pub orientation: String,
+}
+
+impl UcdFile for VerticalOrientation {
+    fn relative_file_path() -> &'static Path {
+        Path::new("VerticalOrientation.txt")
+    }
+}
+
+impl UcdFileByCodepoint for VerticalOrientation {
+    fn codepoints(&self) -> CodepointIter {
+        self.codepoints.into_iter()
+    }
+}
+
+impl FromStr for VerticalOrientation {
+    type Err = Error;
+
+    fn from_str(line: &str) -> Result<VerticalOrientation, Error> {
+        let (codepoints, orientation) = parse_codepoint_association(line)?;
+        Ok(VerticalOrientation {
+            codepoints,
+            orientation: orientation.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VerticalOrientation;
+
+    #[test]
+    fn parse_single() {
+        let line = "00A7          ; U  # Po         SECTION SIGN\n";
+        let row: VerticalOrientation = line.parse().unwrap();
+        assert_eq!(row.codepoints, 0x00A7);
+        assert_eq!(row.orientation, "U");
+    }
+
+    #[test]
+    fn parse_range() {
+        let line = "3008..3011    ; Tr # Ps   [10] LEFT ANGLE BRACKET..RIGHT BLACK LENTICULAR BRACKET\n";
+        let row: VerticalOrientation = line.parse().unwrap();
+        assert_eq!(row.codepoints, (0x3008, 0x3011));
+        assert_eq!(row.orientation, "Tr");
+    }
+}